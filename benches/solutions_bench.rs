@@ -0,0 +1,49 @@
+use advent_of_code::solutions::YEARS;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::fs;
+
+/// Benchmarks parsing and both parts of every day registered in `YEARS`,
+/// against its input under `./inputs/<year>` (days without an input are skipped).
+/// The parts are timed on input parsed once upfront, so they only measure solving.
+///
+/// Run this benchmark using
+/// ```shell
+/// $ cargo bench
+/// ```
+///
+/// Or only for a single day using
+/// ```shell
+/// $ cargo bench -- 2025_day09
+/// ```
+fn solutions(c: &mut Criterion) {
+    for &(year, days) in YEARS {
+        for &(day, solution) in days {
+            let Ok(input) = fs::read_to_string(format!("./inputs/{year}/day_{day:0>2}.txt")) else {
+                continue;
+            };
+            let mut group = c.benchmark_group(format!("{year}_day{day:0>2}"));
+
+            group.bench_function("parse", |b| {
+                b.iter(|| solution.parse(&input));
+            });
+
+            let parsed = solution.with_parsed(&input, &mut |solve| {
+                group.bench_function("part1", |b| {
+                    b.iter(|| solve(1));
+                });
+
+                group.bench_function("part2", |b| {
+                    b.iter(|| solve(2));
+                });
+            });
+            if let Err(e) = parsed {
+                eprintln!("Skipping day {day} of {year}, its input does not parse: {e}");
+            }
+
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
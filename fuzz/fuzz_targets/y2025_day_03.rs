@@ -0,0 +1,8 @@
+#![no_main]
+
+use advent_of_code::fuzz;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = fuzz::solve(2025, 3, data);
+});
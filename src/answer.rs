@@ -0,0 +1,96 @@
+use std::fmt::{Display, Formatter};
+
+/// Answer to a day's part, whatever type the part computes it as.
+///
+/// Converting into this allows runners to print, compare and record answers of every day alike,
+/// comparing is done on the printed value, so e.g. `Unsigned(3)` and `Signed(3)` are equal.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(i128),
+    String(String),
+    /// For parts without an answer to compute, like the last day's part 2
+    Unit,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(answer) => write!(f, "{answer}"),
+            Answer::Signed(answer) => write!(f, "{answer}"),
+            Answer::Big(answer) => write!(f, "{answer}"),
+            Answer::String(answer) => write!(f, "{answer}"),
+            Answer::Unit => Ok(()),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().as_str() == other
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident as $target:ty: $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(answer: $source) -> Self {
+                    Answer::$variant(answer as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+impl_from!(Signed as i64: i8, i16, i32, i64, isize);
+impl_from!(Big as i128: i128);
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::String(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::String(answer.to_string())
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_conversions() {
+        assert_eq!("3", Answer::from(3_u32).to_string());
+        assert_eq!("-3", Answer::from(-3_i8).to_string());
+        assert_eq!(
+            "170141183460469231731687303715884105727",
+            Answer::from(i128::MAX).to_string()
+        );
+        assert_eq!("abc", Answer::from("abc").to_string());
+        assert_eq!("", Answer::from(()).to_string());
+
+        assert_eq!(Answer::from(3_usize), Answer::from(3_i64));
+        assert_ne!(Answer::from(3_usize), Answer::from(4_u64));
+        assert_eq!(Answer::from(3_u64), *"3");
+    }
+}
@@ -0,0 +1,116 @@
+use crate::answer::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+pub const ANSWERS_PATH: &str = "./answers.toml";
+
+/// Recorded answers per year, day and part, stored in `answers.toml` as
+/// ```toml
+/// [2025.day_01]
+/// part_1 = "1123"
+/// part_2 = "6695"
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<String>,
+}
+
+impl Answers {
+    /// Loads the manifest at `path`, a missing file simply has no answers recorded yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(answers) => Ok(toml::from_str(&answers)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: i32, day: u8, part: u8) -> Option<&str> {
+        let answers = self.years.get(&year.to_string())?.get(&Self::key(day))?;
+        match part {
+            1 => answers.part_1.as_deref(),
+            2 => answers.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn record(&mut self, year: i32, day: u8, part: u8, answer: &Answer) {
+        let days = self.years.entry(year.to_string()).or_default();
+        let answers = days.entry(Self::key(day)).or_default();
+        match part {
+            1 => answers.part_1 = Some(answer.to_string()),
+            2 => answers.part_2 = Some(answer.to_string()),
+            _ => panic!("Invalid part {part}, expected 1 or 2"),
+        }
+    }
+
+    fn key(day: u8) -> String {
+        format!("day_{day:0>2}")
+    }
+}
+
+/// Personal input and recorded answer of a day's part, for the `*_solution` tests.
+///
+/// Returns `None` when either is missing, so these tests are skipped rather than failed
+/// on a checkout without personal inputs.
+#[cfg(test)]
+pub(crate) fn expected<T: std::str::FromStr>(year: i32, day: u8, part: u8) -> Option<(String, T)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let Ok(input) = fs::read_to_string(root.join(format!("inputs/{year}/day_{day:0>2}.txt")))
+    else {
+        eprintln!("Skipping day {day:0>2} part {part}: no input");
+        return None;
+    };
+    let answers = Answers::load(root.join(ANSWERS_PATH)).unwrap();
+    let Some(answer) = answers.get(year, day, part) else {
+        eprintln!("Skipping day {day:0>2} part {part}: no recorded answer");
+        return None;
+    };
+    let answer = answer
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid recorded answer for day {day:0>2} part {part}"));
+
+    Some((input, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(2025, 1, 1, &Answer::Unsigned(1123));
+        answers.record(2025, 12, 1, &Answer::Unsigned(565));
+
+        let manifest = toml::to_string(&answers).unwrap();
+
+        assert_eq!(
+            "[2025.day_01]\npart_1 = \"1123\"\n\n[2025.day_12]\npart_1 = \"565\"\n",
+            manifest
+        );
+        assert_eq!(answers, toml::from_str(&manifest).unwrap());
+        assert_eq!(Some("1123"), answers.get(2025, 1, 1));
+        assert_eq!(None, answers.get(2025, 1, 2));
+        assert_eq!(None, answers.get(2025, 2, 1));
+        assert_eq!(None, answers.get(2024, 1, 1));
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+pub const BASELINE_PATH: &str = "./bench_baseline.json";
+
+/// Median solve time in nanoseconds per year, day and part, recorded per git commit in `bench_baseline.json` as
+/// ```json
+/// [
+///   {
+///     "commit": "513ffca",
+///     "years": {
+///       "2025": {
+///         "day_01": { "part_1": 81250, "part_2": 1532000 }
+///       }
+///     }
+///   }
+/// ]
+/// ```
+/// Runs are kept in the order they were recorded, so the last one is the most recent.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline {
+    runs: Vec<BenchRun>,
+}
+
+/// Medians measured for a single commit
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub commit: String,
+    years: BTreeMap<String, BTreeMap<String, DayMedians>>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct DayMedians {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<u64>,
+}
+
+/// Median of a part measured now, next to the one of the same part in the baseline
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Comparison {
+    pub year: i32,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, a missing file simply has no runs recorded yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(baseline) => Ok(serde_json::from_str(&baseline)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    /// Records `run`, replacing any earlier run of the same commit
+    pub fn record(&mut self, run: BenchRun) {
+        self.runs.retain(|other| other.commit != run.commit);
+        self.runs.push(run);
+    }
+
+    /// The most recently recorded run of a commit other than `commit`, to compare `commit` against
+    pub fn latest_before(&self, commit: &str) -> Option<&BenchRun> {
+        self.runs.iter().rev().find(|run| run.commit != commit)
+    }
+}
+
+impl BenchRun {
+    pub fn new(commit: &str) -> Self {
+        Self {
+            commit: commit.to_string(),
+            years: BTreeMap::new(),
+        }
+    }
+
+    pub fn get(&self, year: i32, day: u8, part: u8) -> Option<Duration> {
+        let medians = self.years.get(&year.to_string())?.get(&Self::key(day))?;
+        let median = match part {
+            1 => medians.part_1,
+            2 => medians.part_2,
+            _ => None,
+        };
+        median.map(Duration::from_nanos)
+    }
+
+    pub fn record(&mut self, year: i32, day: u8, part: u8, median: Duration) {
+        let days = self.years.entry(year.to_string()).or_default();
+        let medians = days.entry(Self::key(day)).or_default();
+        let median = Some(median.as_nanos() as u64);
+        match part {
+            1 => medians.part_1 = median,
+            2 => medians.part_2 = median,
+            _ => panic!("Invalid part {part}, expected 1 or 2"),
+        }
+    }
+
+    /// Every part measured in both this run and `baseline`, ordered by year, day and part
+    pub fn compare(&self, baseline: &BenchRun) -> Vec<Comparison> {
+        self.years
+            .iter()
+            .filter_map(|(year, days)| Some((year.parse::<i32>().ok()?, days)))
+            .flat_map(|(year, days)| {
+                days.keys()
+                    .filter_map(|key| key.strip_prefix("day_")?.parse::<u8>().ok())
+                    .flat_map(move |day| [(year, day, 1), (year, day, 2)])
+            })
+            .filter_map(|(year, day, part)| {
+                Some(Comparison {
+                    year,
+                    day,
+                    part,
+                    baseline: baseline.get(year, day, part)?,
+                    current: self.get(year, day, part)?,
+                })
+            })
+            .collect()
+    }
+
+    fn key(day: u8) -> String {
+        format!("day_{day:0>2}")
+    }
+}
+
+impl Comparison {
+    /// Relative change of the median in percent, positive when it got slower
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+        let current = self.current.as_secs_f64();
+
+        if baseline == 0.0 {
+            return if current == 0.0 { 0.0 } else { f64::INFINITY };
+        }
+        (current - baseline) / baseline * 100.0
+    }
+
+    /// Whether the part got slower by more than `threshold` percent
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip() {
+        let mut run = BenchRun::new("513ffca");
+        run.record(2025, 1, 1, Duration::from_micros(81));
+        run.record(2025, 1, 2, Duration::from_millis(2));
+        let mut baseline = Baseline::default();
+        baseline.record(run.clone());
+
+        let json = serde_json::to_string(&baseline).unwrap();
+
+        assert_eq!(baseline, serde_json::from_str(&json).unwrap());
+        assert_eq!(Some(Duration::from_micros(81)), run.get(2025, 1, 1));
+        assert_eq!(None, run.get(2025, 2, 1));
+    }
+
+    #[test]
+    fn baseline_keeps_latest_run_per_commit() {
+        let mut baseline = Baseline::default();
+        let mut run = BenchRun::new("513ffca");
+        baseline.record(run.clone());
+        baseline.record(BenchRun::new("e9d0cff"));
+        run.record(2025, 1, 1, Duration::from_micros(81));
+        baseline.record(run.clone());
+
+        assert_eq!(Some(&run), baseline.latest_before("e9d0cff"));
+        assert_eq!("e9d0cff", baseline.latest_before("513ffca").unwrap().commit);
+        assert_eq!(2, baseline.runs.len());
+    }
+
+    #[test]
+    fn baseline_compare_flags_regressions() {
+        let mut baseline = BenchRun::new("513ffca");
+        baseline.record(2025, 1, 1, Duration::from_millis(100));
+        baseline.record(2025, 1, 2, Duration::from_millis(100));
+        baseline.record(2025, 2, 1, Duration::from_millis(100));
+        let mut current = BenchRun::new("e9d0cff");
+        current.record(2025, 1, 1, Duration::from_millis(105));
+        current.record(2025, 1, 2, Duration::from_millis(150));
+        current.record(2025, 3, 1, Duration::from_millis(100));
+
+        let comparisons = current.compare(&baseline);
+        let regressions = comparisons
+            .iter()
+            .filter(|comparison| comparison.is_regression(10.0))
+            .map(|comparison| (comparison.day, comparison.part))
+            .collect::<Vec<_>>();
+
+        assert_eq!(2, comparisons.len());
+        assert_eq!(vec![(1, 2)], regressions);
+        assert!((comparisons[0].change() - 5.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,1099 @@
+use std::error::Error;
+use std::fs;
+use std::hint::black_box;
+use std::io::Read;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use std::{env, io, process};
+
+use serde::Serialize;
+
+use advent_of_code::answer::Answer;
+use advent_of_code::answers::{ANSWERS_PATH, Answers};
+use advent_of_code::baseline::{BASELINE_PATH, Baseline, BenchRun};
+use advent_of_code::client::{CONFIG_PATH, Client, ClientConfig, Outcome, Verdict};
+use advent_of_code::generate;
+use advent_of_code::guesses::{GUESSES_PATH, Guesses};
+use advent_of_code::memory::TrackingAllocator;
+use advent_of_code::solutions::{self, Days, day};
+
+/// Binary to run the solutions of any day against any input.
+///
+/// # How to run
+///
+/// This will run both parts of "Day 09" against `./inputs/2025/day_09.txt`:
+/// ```shell
+/// $ cargo run --release --bin aoc -- run 9
+/// ```
+///
+/// This will only run part 2 of "Day 09" against a specific file:
+/// ```shell
+/// $ cargo run --release --bin aoc -- run 9 --part 2 --input path/to/input.txt
+/// ```
+///
+/// Passing `-` as input reads it from stdin instead:
+/// ```shell
+/// $ cat path/to/input.txt | cargo run --release --bin aoc -- run 9 --input -
+/// ```
+///
+/// This will run all registered days against their input under `./inputs/2025`,
+/// followed by a summary table:
+/// ```shell
+/// $ cargo run --release --bin aoc -- run --all
+/// ```
+///
+/// Passing `--format json` or `--format csv` instead only prints the results per part
+/// (answer, parse time, solve time and peak memory) in that format, e.g. for dashboards:
+/// ```shell
+/// $ cargo run --release --bin aoc -- run --all --format json > results.json
+/// ```
+///
+/// Building with the `alloc-profile` feature also reports the number of allocations and bytes allocated per part:
+/// ```shell
+/// $ cargo run --release --features alloc-profile --bin aoc -- run --all
+/// ```
+///
+/// This will check all answers recorded in `./answers.toml` against the current solutions,
+/// passing `--record` also records the answers of parts that have none recorded yet:
+/// ```shell
+/// $ cargo run --release --bin aoc -- verify --record
+/// ```
+///
+/// This will measure the median solve time of every part on its already parsed input under `./inputs/2025`,
+/// passing `--record` stores these medians for the current git commit in `./bench_baseline.json`:
+/// ```shell
+/// $ cargo run --release --bin aoc -- bench --record
+/// ```
+///
+/// Passing `--compare` instead compares them against the most recent other commit in `./bench_baseline.json`,
+/// failing when any part got slower by more than `--threshold` percent (10 by default).
+/// `--samples` sets how many times each part is solved to take the median of (11 by default):
+/// ```shell
+/// $ cargo run --release --bin aoc -- bench --compare --threshold 20 --samples 25
+/// ```
+///
+/// This will print a random input of "Day 09" with 2000 red tiles, to stress the solution on.
+/// What `--size` counts (1000 by default) differs per day, the same `--seed` (0 by default) always gives the same input.
+/// Passing `--output` writes it to that file instead:
+/// ```shell
+/// $ cargo run --release --bin aoc -- generate 9 --size 2000 --seed 42 | cargo run --release --bin aoc -- run 9 --input -
+/// ```
+///
+/// This will download the personal input of "Day 09" to `./inputs/2025/day_09.txt`,
+/// unless that was already done before, passing `--force` downloads it again:
+/// ```shell
+/// $ cargo run --release --bin aoc -- fetch 9
+/// ```
+///
+/// This will submit the answer to part 2 of "Day 09" for its input under `./inputs/2025`,
+/// recording it in `./answers.toml` when correct:
+/// ```shell
+/// $ cargo run --release --bin aoc -- submit 9 2
+/// ```
+///
+/// Every command works on the most recent registered year, unless another one is passed with `--year`:
+/// ```shell
+/// $ cargo run --release --bin aoc -- run --all --year 2024
+/// ```
+///
+/// Every submitted answer is logged in `./guesses.toml`, answers known to be wrong from it are not submitted again.
+///
+/// Downloading and submitting need the session cookie of a logged-in browser, set in `./aoc.toml` as
+/// `session = "..."` or in the `AOC_SESSION` environment variable.
+/// Likewise `base_url` or `AOC_BASE_URL` points them at a server other than the Advent of Code website.
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        process::exit(1);
+    });
+
+    if let Err(e) = run(config) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    year: i32,
+    command: Command,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run {
+        day: Option<u8>,
+        part: Option<u8>,
+        input: Option<String>,
+        format: Format,
+    },
+    Verify {
+        record: bool,
+    },
+    Bench {
+        record: bool,
+        compare: bool,
+        threshold: f64,
+        samples: usize,
+    },
+    Generate {
+        day: u8,
+        size: usize,
+        seed: u64,
+        output: Option<String>,
+    },
+    Fetch {
+        day: u8,
+        force: bool,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Results of a single part, as printed by `run --all`
+#[derive(Debug, Clone, Serialize)]
+struct Row {
+    year: i32,
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_time_ns: u64,
+    solve_time_ns: u64,
+    peak_memory_bytes: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocated_bytes: Option<usize>,
+}
+
+impl Config {
+    fn build(args: &[String]) -> Result<Config, &'static str> {
+        let (year, args) = Self::build_year(args.get(1..).unwrap_or_default())?;
+
+        let command = match args.first().map(String::as_str) {
+            Some("run") => Self::build_run(&args[1..]),
+            Some("verify") => Self::build_verify(&args[1..]),
+            Some("bench") => Self::build_bench(&args[1..]),
+            Some("generate") => Self::build_generate(&args[1..]),
+            Some("fetch") => Self::build_fetch(&args[1..]),
+            Some("submit") => Self::build_submit(&args[1..]),
+            Some(_) => Err(
+                "unknown command, expected `run`, `verify`, `bench`, `generate`, `fetch` or `submit`",
+            ),
+            None => Err(
+                "missing command, expected `run`, `verify`, `bench`, `generate`, `fetch` or `submit`",
+            ),
+        }?;
+
+        Ok(Config { year, command })
+    }
+
+    /// Takes `--year` out of `args`, defaulting to the most recent registered year
+    fn build_year(args: &[String]) -> Result<(i32, Vec<String>), &'static str> {
+        let mut year = None;
+        let mut rest = vec![];
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => {
+                    let value = args.next().ok_or("missing value for `--year`")?;
+                    year = Some(i32::from_str(value).map_err(|_| "invalid year")?);
+                }
+                _ => rest.push(arg.clone()),
+            }
+        }
+
+        Ok((year.unwrap_or_else(solutions::latest_year), rest))
+    }
+
+    fn build_run(args: &[String]) -> Result<Command, &'static str> {
+        let mut day = None;
+        let mut all = false;
+        let mut part = None;
+        let mut input = None;
+        let mut format = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--part" => {
+                    let value = args.next().ok_or("missing value for `--part`")?;
+                    part = match u8::from_str(value) {
+                        Ok(part @ (1 | 2)) => Some(part),
+                        _ => return Err("invalid part, expected 1 or 2"),
+                    };
+                }
+                "--input" => {
+                    let value = args.next().ok_or("missing value for `--input`")?;
+                    input = Some(value.clone());
+                }
+                "--format" => {
+                    let value = args.next().ok_or("missing value for `--format`")?;
+                    format = match value.as_str() {
+                        "table" => Some(Format::Table),
+                        "json" => Some(Format::Json),
+                        "csv" => Some(Format::Csv),
+                        _ => return Err("invalid format, expected `table`, `json` or `csv`"),
+                    };
+                }
+                value => day = Some(u8::from_str(value).map_err(|_| "invalid day")?),
+            }
+        }
+
+        match (day, all) {
+            (Some(_), true) => Err("either pass a day or `--all`, not both"),
+            (None, false) => Err("missing day, or `--all` to run every day"),
+            (None, true) if input.is_some() => Err("`--input` cannot be combined with `--all`"),
+            (Some(_), false) if format.is_some() => Err("`--format` only applies to `--all`"),
+            _ => Ok(Command::Run {
+                day,
+                part,
+                input,
+                format: format.unwrap_or(Format::Table),
+            }),
+        }
+    }
+
+    fn build_verify(args: &[String]) -> Result<Command, &'static str> {
+        match args {
+            [] => Ok(Command::Verify { record: false }),
+            [flag] if flag == "--record" => Ok(Command::Verify { record: true }),
+            _ => Err("invalid arguments, `verify` only accepts `--record`"),
+        }
+    }
+
+    fn build_bench(args: &[String]) -> Result<Command, &'static str> {
+        let mut record = false;
+        let mut compare = false;
+        let mut threshold = 10.0;
+        let mut samples = 11;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => record = true,
+                "--compare" => compare = true,
+                "--threshold" => {
+                    let value = args.next().ok_or("missing value for `--threshold`")?;
+                    threshold = match f64::from_str(value) {
+                        Ok(threshold) if threshold > 0.0 && threshold.is_finite() => threshold,
+                        _ => return Err("invalid threshold, expected a positive percentage"),
+                    };
+                }
+                "--samples" => {
+                    let value = args.next().ok_or("missing value for `--samples`")?;
+                    samples = match usize::from_str(value) {
+                        Ok(samples) if samples > 0 => samples,
+                        _ => return Err("invalid number of samples, expected at least 1"),
+                    };
+                }
+                _ => {
+                    return Err(
+                        "invalid arguments, `bench` only accepts `--record`, `--compare`, `--threshold` and `--samples`",
+                    );
+                }
+            }
+        }
+
+        Ok(Command::Bench {
+            record,
+            compare,
+            threshold,
+            samples,
+        })
+    }
+
+    fn build_generate(args: &[String]) -> Result<Command, &'static str> {
+        let mut day = None;
+        let mut size = 1_000;
+        let mut seed = 0;
+        let mut output = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--size" => {
+                    let value = args.next().ok_or("missing value for `--size`")?;
+                    size = match usize::from_str(value) {
+                        Ok(size) if size > 0 => size,
+                        _ => return Err("invalid size, expected at least 1"),
+                    };
+                }
+                "--seed" => {
+                    let value = args.next().ok_or("missing value for `--seed`")?;
+                    seed = u64::from_str(value).map_err(|_| "invalid seed")?;
+                }
+                "--output" => {
+                    let value = args.next().ok_or("missing value for `--output`")?;
+                    output = Some(value.clone());
+                }
+                value => day = Some(u8::from_str(value).map_err(|_| "invalid day")?),
+            }
+        }
+
+        let day = day.ok_or("missing day to generate an input for")?;
+        Ok(Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        })
+    }
+
+    fn build_fetch(args: &[String]) -> Result<Command, &'static str> {
+        let mut day = None;
+        let mut force = false;
+
+        for arg in args {
+            match arg.as_str() {
+                "--force" => force = true,
+                value => day = Some(u8::from_str(value).map_err(|_| "invalid day")?),
+            }
+        }
+
+        let day = day.ok_or("missing day to fetch the input of")?;
+        Ok(Command::Fetch { day, force })
+    }
+
+    fn build_submit(args: &[String]) -> Result<Command, &'static str> {
+        let [day, part] = args else {
+            return Err("invalid arguments, `submit` expects a day and a part");
+        };
+
+        let day = u8::from_str(day).map_err(|_| "invalid day")?;
+        let part = match u8::from_str(part) {
+            Ok(part @ (1 | 2)) => part,
+            _ => return Err("invalid part, expected 1 or 2"),
+        };
+        Ok(Command::Submit { day, part })
+    }
+}
+
+fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let year = config.year;
+
+    match config.command {
+        Command::Run {
+            day: Some(day),
+            part,
+            input,
+            ..
+        } => run_day(year, day, part, input),
+        Command::Run {
+            day: None,
+            part,
+            format,
+            ..
+        } => run_all(year, part, format),
+        Command::Verify { record } => verify(year, record),
+        Command::Bench {
+            record,
+            compare,
+            threshold,
+            samples,
+        } => bench(year, record, compare, threshold, samples),
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => generate(year, day, size, seed, output),
+        Command::Fetch { day, force } => fetch(year, day, force),
+        Command::Submit { day, part } => submit(year, day, part),
+    }
+}
+
+fn run_day(
+    year: i32,
+    day_nr: u8,
+    part: Option<u8>,
+    input: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let solution = day(year, day_nr).ok_or(format!("day {day_nr} of {year} is not registered"))?;
+    let input = match input.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input
+        }
+        Some(path) => fs::read_to_string(path)?,
+        None => fs::read_to_string(input_path(year, day_nr))?,
+    };
+
+    for part in parts(part) {
+        let run = solution.run(part, &input);
+        let answer = run
+            .answer
+            .map_err(|e| format!("invalid input for day {day_nr:0>2}, {e}"))?;
+        let elapsed = run.parse_time + run.solve_time;
+        let peak_memory = format_bytes(run.memory.peak);
+        match (run.memory.allocations, run.memory.allocated_bytes) {
+            (Some(allocations), Some(allocated_bytes)) => {
+                let allocated = format_bytes(allocated_bytes);
+                println!(
+                    "Day {day_nr:0>2} part {part}: {answer} ({elapsed:.2?}, {allocations} allocations, {allocated} allocated, {peak_memory} peak)"
+                );
+            }
+            _ => println!("Day {day_nr:0>2} part {part}: {answer} ({elapsed:.2?})"),
+        }
+    }
+
+    Ok(())
+}
+
+fn run_all(year: i32, part: Option<u8>, format: Format) -> Result<(), Box<dyn Error>> {
+    let mut rows = vec![];
+
+    for &(day_nr, solution) in registered_days(year)? {
+        let Ok(input) = fs::read_to_string(input_path(year, day_nr)) else {
+            eprintln!(
+                "Skipping day {day_nr:0>2}: no input at {}",
+                input_path(year, day_nr)
+            );
+            continue;
+        };
+
+        for part in parts(part) {
+            let run = solution.run(part, &input);
+            let Ok(answer) = run.answer.inspect_err(|e| {
+                eprintln!("Skipping day {day_nr:0>2} part {part}: invalid input, {e}");
+            }) else {
+                continue;
+            };
+            if format == Format::Table {
+                let elapsed = run.parse_time + run.solve_time;
+                println!("Day {day_nr:0>2} part {part}: {answer} ({elapsed:.2?})");
+            }
+
+            rows.push(Row {
+                year,
+                day: day_nr,
+                part,
+                answer: answer.to_string(),
+                parse_time_ns: run.parse_time.as_nanos() as u64,
+                solve_time_ns: run.solve_time.as_nanos() as u64,
+                peak_memory_bytes: run.memory.peak,
+                allocations: run.memory.allocations,
+                allocated_bytes: run.memory.allocated_bytes,
+            });
+        }
+    }
+
+    match format {
+        Format::Table => print_table(&rows),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+        Format::Csv => print_csv(&rows),
+    }
+
+    Ok(())
+}
+
+fn print_table(rows: &[Row]) {
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    // Only profiled builds count allocations, see the `alloc-profile` feature
+    let profiled = rows.iter().any(|row| row.allocations.is_some());
+
+    println!();
+    print!(
+        "| Day | Part | {:<answer_width$} | {:>12} | {:>12} | {:>11} |",
+        "Answer", "Parse", "Solve", "Peak memory"
+    );
+    if profiled {
+        print!(" {:>11} | {:>11} |", "Allocations", "Allocated");
+    }
+    println!();
+    print!(
+        "|-----|------|-{:-<answer_width$}-|-{:->12}-|-{:->12}-|-{:->11}-|",
+        "", "", "", ""
+    );
+    if profiled {
+        print!("-{:->11}-|-{:->11}-|", "", "");
+    }
+    println!();
+    for row in rows {
+        let Row { day, part, .. } = row;
+        let answer = &row.answer;
+        let parse_time = format!("{:.2?}", Duration::from_nanos(row.parse_time_ns));
+        let solve_time = format!("{:.2?}", Duration::from_nanos(row.solve_time_ns));
+        let peak_memory = format_bytes(row.peak_memory_bytes);
+        print!(
+            "| {day:>3} | {part:>4} | {answer:<answer_width$} | {parse_time:>12} | {solve_time:>12} | {peak_memory:>11} |"
+        );
+        if let (Some(allocations), Some(allocated_bytes)) = (row.allocations, row.allocated_bytes) {
+            let allocated = format_bytes(allocated_bytes);
+            print!(" {allocations:>11} | {allocated:>11} |");
+        }
+        println!();
+    }
+
+    let total = rows
+        .iter()
+        .map(|row| Duration::from_nanos(row.parse_time_ns + row.solve_time_ns))
+        .sum::<Duration>();
+    println!("Total time: {total:.2?}");
+}
+
+fn print_csv(rows: &[Row]) {
+    let profiled = rows.iter().any(|row| row.allocations.is_some());
+
+    print!("year,day,part,answer,parse_time_ns,solve_time_ns,peak_memory_bytes");
+    if profiled {
+        print!(",allocations,allocated_bytes");
+    }
+    println!();
+    for row in rows {
+        let answer = if row.answer.contains([',', '"', '\n']) {
+            format!("\"{}\"", row.answer.replace('"', "\"\""))
+        } else {
+            row.answer.clone()
+        };
+        print!(
+            "{},{},{},{answer},{},{},{}",
+            row.year,
+            row.day,
+            row.part,
+            row.parse_time_ns,
+            row.solve_time_ns,
+            row.peak_memory_bytes
+        );
+        if let (Some(allocations), Some(allocated_bytes)) = (row.allocations, row.allocated_bytes) {
+            print!(",{allocations},{allocated_bytes}");
+        }
+        println!();
+    }
+}
+
+fn verify(year: i32, record: bool) -> Result<(), Box<dyn Error>> {
+    let mut answers = Answers::load(ANSWERS_PATH)?;
+    let mut mismatches = 0;
+    let mut recorded = 0;
+
+    for &(day_nr, solution) in registered_days(year)? {
+        let Ok(input) = fs::read_to_string(input_path(year, day_nr)) else {
+            eprintln!(
+                "Skipping day {day_nr:0>2}: no input at {}",
+                input_path(year, day_nr)
+            );
+            continue;
+        };
+
+        for part in [1, 2] {
+            let answer = solution.run(part, &input).answer;
+            let answer = answer.map_err(|e| format!("invalid input for day {day_nr:0>2}, {e}"))?;
+
+            match check(&mut answers, year, day_nr, part, &answer, record) {
+                Check::Ok => println!("Day {day_nr:0>2} part {part}: OK ({answer})"),
+                Check::Mismatch(expected) => {
+                    println!(
+                        "Day {day_nr:0>2} part {part}: MISMATCH ({answer}, expected {expected})"
+                    );
+                    mismatches += 1;
+                }
+                Check::Recorded => {
+                    println!("Day {day_nr:0>2} part {part}: RECORDED ({answer})");
+                    recorded += 1;
+                }
+                Check::Unrecorded => {
+                    println!("Day {day_nr:0>2} part {part}: UNRECORDED ({answer})")
+                }
+                Check::Unanswered => {}
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers.save(ANSWERS_PATH)?;
+    }
+    if mismatches > 0 {
+        return Err(format!("{mismatches} answer(s) did not match the recorded ones").into());
+    }
+
+    Ok(())
+}
+
+/// Outcome of checking the answer of a part against the recorded one
+#[derive(Debug, Eq, PartialEq)]
+enum Check {
+    Ok,
+    /// Holding the recorded answer
+    Mismatch(String),
+    /// Nothing recorded yet, so the answer got recorded
+    Recorded,
+    /// Nothing recorded yet, and recording was not asked for
+    Unrecorded,
+    /// The part has no answer to check, like the last day's part 2
+    Unanswered,
+}
+
+/// Checks `answer` against the one recorded in `answers`, recording it if there is none yet and `record` is set
+fn check(
+    answers: &mut Answers,
+    year: i32,
+    day_nr: u8,
+    part: u8,
+    answer: &Answer,
+    record: bool,
+) -> Check {
+    match answers.get(year, day_nr, part) {
+        Some(expected) if *answer == *expected => Check::Ok,
+        Some(expected) => Check::Mismatch(expected.to_string()),
+        None if *answer == Answer::Unit => Check::Unanswered,
+        None if record => {
+            answers.record(year, day_nr, part, answer);
+            Check::Recorded
+        }
+        None => Check::Unrecorded,
+    }
+}
+
+fn bench(
+    year: i32,
+    record: bool,
+    compare: bool,
+    threshold: f64,
+    samples: usize,
+) -> Result<(), Box<dyn Error>> {
+    // Only recording and comparing need the commit, so a plain run also works outside of a git checkout
+    let commit = match record || compare {
+        true => git_commit()?,
+        false => String::new(),
+    };
+    let mut current = BenchRun::new(&commit);
+
+    for &(day_nr, solution) in registered_days(year)? {
+        let Ok(input) = fs::read_to_string(input_path(year, day_nr)) else {
+            eprintln!(
+                "Skipping day {day_nr:0>2}: no input at {}",
+                input_path(year, day_nr)
+            );
+            continue;
+        };
+
+        let parsed = solution.with_parsed(&input, &mut |solve| {
+            for part in [1, 2] {
+                let mut times = (0..samples)
+                    .map(|_| {
+                        let start = Instant::now();
+                        let _ = black_box(solve(part));
+                        start.elapsed()
+                    })
+                    .collect::<Vec<_>>();
+                times.sort();
+                let median = times[times.len() / 2];
+
+                println!("Day {day_nr:0>2} part {part}: {median:.2?}");
+                current.record(year, day_nr, part, median);
+            }
+        });
+        if let Err(e) = parsed {
+            eprintln!("Skipping day {day_nr:0>2}: invalid input, {e}");
+        }
+    }
+
+    let mut baseline = Baseline::load(BASELINE_PATH)?;
+    let regressions = if compare {
+        let previous = baseline.latest_before(&commit).ok_or(format!(
+            "no other commit in {BASELINE_PATH} to compare against, record one with `--record` first"
+        ))?;
+        print_comparison(&current, previous, threshold)
+    } else {
+        0
+    };
+
+    if record {
+        baseline.record(current);
+        baseline.save(BASELINE_PATH)?;
+        println!("Recorded the medians of {commit} in {BASELINE_PATH}");
+    }
+    if regressions > 0 {
+        let error = format!("{regressions} part(s) got more than {threshold}% slower");
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Prints how every part changed compared to `previous`, returning the number of regressions
+fn print_comparison(current: &BenchRun, previous: &BenchRun, threshold: f64) -> usize {
+    let comparisons = current.compare(previous);
+
+    println!();
+    println!(
+        "Compared against {} (threshold {threshold}%):",
+        previous.commit
+    );
+    for comparison in &comparisons {
+        let day = comparison.day;
+        let part = comparison.part;
+        let baseline = format!("{:.2?}", comparison.baseline);
+        let median = format!("{:.2?}", comparison.current);
+        let change = format!("{:+.1}%", comparison.change());
+        let verdict = match comparison.is_regression(threshold) {
+            true => "REGRESSION",
+            false => "OK",
+        };
+        println!("Day {day:0>2} part {part}: {baseline:>12} -> {median:>12} {change:>8} {verdict}");
+    }
+
+    comparisons
+        .iter()
+        .filter(|comparison| comparison.is_regression(threshold))
+        .count()
+}
+
+fn generate(
+    year: i32,
+    day_nr: u8,
+    size: usize,
+    seed: u64,
+    output: Option<String>,
+) -> Result<(), Box<dyn Error>> {
+    let input = generate::generate(year, day_nr, size, seed)
+        .ok_or(format!("day {day_nr} of {year} has no input generator"))?;
+
+    match output {
+        Some(path) => {
+            fs::write(&path, input)?;
+            eprintln!("Generated an input of size {size} for day {day_nr:0>2} at {path}");
+        }
+        None => print!("{input}"),
+    }
+
+    Ok(())
+}
+
+fn fetch(year: i32, day_nr: u8, force: bool) -> Result<(), Box<dyn Error>> {
+    let path = input_path(year, day_nr);
+    // `scaffold` creates an empty input, which is not worth keeping
+    let cached = fs::read_to_string(&path).is_ok_and(|input| !input.is_empty());
+    if cached && !force {
+        println!("Day {day_nr:0>2}: input already at {path}, pass `--force` to fetch it again");
+        return Ok(());
+    }
+
+    let client = Client::from_config(ClientConfig::load(CONFIG_PATH)?);
+    let input = client.input(year, day_nr)?;
+    fs::create_dir_all(format!("./inputs/{year}"))?;
+    fs::write(&path, input)?;
+    println!("Day {day_nr:0>2}: input fetched to {path}");
+
+    Ok(())
+}
+
+fn submit(year: i32, day_nr: u8, part: u8) -> Result<(), Box<dyn Error>> {
+    let solution = day(year, day_nr).ok_or(format!("day {day_nr} of {year} is not registered"))?;
+    let input = fs::read_to_string(input_path(year, day_nr))?;
+    let answer = solution.run(part, &input).answer;
+    let answer = answer.map_err(|e| format!("invalid input for day {day_nr:0>2}, {e}"))?;
+    if answer == Answer::Unit {
+        return Err(format!("day {day_nr:0>2} part {part} has no answer to submit").into());
+    }
+
+    let mut answers = Answers::load(ANSWERS_PATH)?;
+    match answers.get(year, day_nr, part) {
+        Some(expected) if answer == *expected => {
+            println!("Day {day_nr:0>2} part {part}: already solved ({answer})");
+            return Ok(());
+        }
+        Some(expected) => {
+            let error = format!("{answer} differs from the recorded correct answer {expected}");
+            return Err(error.into());
+        }
+        None => {}
+    }
+
+    let mut guesses = Guesses::load(GUESSES_PATH)?;
+    if let Some(guess) = guesses.ruled_out_by(year, day_nr, part, &answer) {
+        let error = format!(
+            "{answer} is known to be wrong, {} was {}",
+            guess.answer, guess.verdict
+        );
+        return Err(error.into());
+    }
+
+    let client = Client::from_config(ClientConfig::load(CONFIG_PATH)?);
+    let verdict = match client.submit(year, day_nr, part, &answer)? {
+        Outcome::Verdict(verdict) => verdict,
+        Outcome::Wait(wait) => {
+            return Err(format!("answered too recently, wait {wait:?} and submit again").into());
+        }
+        Outcome::WrongLevel => {
+            return Err(format!("part {part} is either already solved, or still locked").into());
+        }
+    };
+
+    println!("Day {day_nr:0>2} part {part}: {answer} is {verdict}");
+    guesses.record(year, day_nr, part, &answer, verdict);
+    guesses.save(GUESSES_PATH)?;
+    if verdict == Verdict::Correct {
+        answers.record(year, day_nr, part, &answer);
+        answers.save(ANSWERS_PATH)?;
+    }
+
+    Ok(())
+}
+
+/// Short hash of the checked out commit, which benchmark medians are recorded under
+fn git_commit() -> Result<String, Box<dyn Error>> {
+    let output = process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()?;
+    if !output.status.success() {
+        let error = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("could not determine the git commit, {}", error.trim()).into());
+    }
+
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    part.map_or(vec![1, 2], |part| vec![part])
+}
+
+fn registered_days(year: i32) -> Result<Days, String> {
+    solutions::days(year).ok_or(format!("no days of {year} are registered"))
+}
+
+fn input_path(year: i32, day: u8) -> String {
+    format!("./inputs/{year}/day_{day:0>2}.txt")
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..1_024 => format!("{bytes} B"),
+        1_024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1_024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(args: &str) -> Result<Config, &'static str> {
+        let args = ["aoc"]
+            .into_iter()
+            .chain(args.split_whitespace())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        Config::build(&args)
+    }
+
+    fn command(args: &str) -> Result<Command, &'static str> {
+        build(args).map(|config| config.command)
+    }
+
+    #[test]
+    fn build_year() {
+        assert_eq!(solutions::latest_year(), build("run 9").unwrap().year);
+        assert_eq!(2024, build("run --year 2024 9").unwrap().year);
+        assert_eq!(2024, build("--year 2024 verify").unwrap().year);
+        assert_eq!(Err("invalid year"), build("run 9 --year next"));
+        assert_eq!(Err("missing value for `--year`"), build("run 9 --year"));
+        assert_eq!(
+            Err(
+                "missing command, expected `run`, `verify`, `bench`, `generate`, `fetch` or `submit`"
+            ),
+            build("--year 2024")
+        );
+        assert_eq!(
+            Err(
+                "unknown command, expected `run`, `verify`, `bench`, `generate`, `fetch` or `submit`"
+            ),
+            build("solve 9")
+        );
+    }
+
+    #[test]
+    fn build_run() {
+        assert_eq!(
+            Ok(Command::Run {
+                day: Some(9),
+                part: Some(2),
+                input: Some("-".to_string()),
+                format: Format::Table,
+            }),
+            command("run 9 --part 2 --input -")
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: None,
+                format: Format::Csv,
+            }),
+            command("run --all --format csv")
+        );
+
+        assert_eq!(Err("invalid day"), command("run nine"));
+        assert_eq!(
+            Err("invalid part, expected 1 or 2"),
+            command("run 9 --part 3")
+        );
+        assert_eq!(
+            Err("invalid format, expected `table`, `json` or `csv`"),
+            command("run --all --format xml")
+        );
+        assert_eq!(
+            Err("either pass a day or `--all`, not both"),
+            command("run 9 --all")
+        );
+        assert_eq!(
+            Err("missing day, or `--all` to run every day"),
+            command("run --part 1")
+        );
+        assert_eq!(
+            Err("`--input` cannot be combined with `--all`"),
+            command("run --all --input day_09.txt")
+        );
+        assert_eq!(
+            Err("`--format` only applies to `--all`"),
+            command("run 9 --format json")
+        );
+        assert_eq!(Err("missing value for `--input`"), command("run 9 --input"));
+    }
+
+    #[test]
+    fn build_other_commands() {
+        assert_eq!(
+            Ok(Command::Verify { record: true }),
+            command("verify --record")
+        );
+        assert_eq!(
+            Err("invalid arguments, `verify` only accepts `--record`"),
+            command("verify 9")
+        );
+
+        assert_eq!(
+            Ok(Command::Bench {
+                record: false,
+                compare: true,
+                threshold: 5.5,
+                samples: 11,
+            }),
+            command("bench --compare --threshold 5.5")
+        );
+        assert_eq!(
+            Err("invalid threshold, expected a positive percentage"),
+            command("bench --threshold -1")
+        );
+        assert_eq!(
+            Err("invalid threshold, expected a positive percentage"),
+            command("bench --threshold 0")
+        );
+        assert_eq!(
+            Err("invalid threshold, expected a positive percentage"),
+            command("bench --threshold inf")
+        );
+        assert_eq!(
+            Err("invalid number of samples, expected at least 1"),
+            command("bench --samples 0")
+        );
+
+        assert_eq!(
+            Ok(Command::Generate {
+                day: 9,
+                size: 1_000,
+                seed: 42,
+                output: None,
+            }),
+            command("generate 9 --seed 42")
+        );
+        assert_eq!(
+            Err("invalid size, expected at least 1"),
+            command("generate 9 --size 0")
+        );
+        assert_eq!(
+            Err("missing day to generate an input for"),
+            command("generate --size 10")
+        );
+
+        assert_eq!(
+            Ok(Command::Fetch {
+                day: 9,
+                force: true
+            }),
+            command("fetch --force 9")
+        );
+        assert_eq!(
+            Ok(Command::Submit { day: 9, part: 2 }),
+            command("submit 9 2")
+        );
+        assert_eq!(
+            Err("invalid arguments, `submit` expects a day and a part"),
+            command("submit 9")
+        );
+        assert_eq!(Err("invalid part, expected 1 or 2"), command("submit 9 0"));
+    }
+
+    #[test]
+    fn check_answers() {
+        let mut answers = Answers::default();
+        answers.record(2025, 1, 1, &Answer::from(1_123_u32));
+
+        assert_eq!(
+            Check::Ok,
+            check(&mut answers, 2025, 1, 1, &Answer::from(1_123_u64), false)
+        );
+        assert_eq!(
+            Check::Mismatch("1123".to_string()),
+            check(&mut answers, 2025, 1, 1, &Answer::from(-1_123_i32), true)
+        );
+        assert_eq!(
+            Check::Unanswered,
+            check(&mut answers, 2025, 12, 2, &Answer::Unit, true)
+        );
+        assert_eq!(
+            Check::Unrecorded,
+            check(&mut answers, 2025, 1, 2, &Answer::from(6_695_u32), false)
+        );
+        assert_eq!(None, answers.get(2025, 1, 2));
+        assert_eq!(
+            Check::Recorded,
+            check(&mut answers, 2025, 1, 2, &Answer::from(6_695_u32), true)
+        );
+        assert_eq!(Some("6695"), answers.get(2025, 1, 2));
+        assert_eq!(None, answers.get(2025, 12, 2));
+    }
+
+    #[test]
+    fn comparison_counts_regressions() {
+        let mut previous = BenchRun::new("513ffca");
+        previous.record(2025, 1, 1, Duration::from_micros(100));
+        previous.record(2025, 1, 2, Duration::from_micros(100));
+        previous.record(2025, 2, 1, Duration::from_micros(100));
+
+        let mut current = BenchRun::new("a1b2c3d");
+        current.record(2025, 1, 1, Duration::from_micros(150));
+        current.record(2025, 1, 2, Duration::from_micros(80));
+        current.record(2025, 2, 1, Duration::from_micros(108));
+        // Only measured now, so nothing to compare against
+        current.record(2025, 3, 1, Duration::from_micros(500));
+
+        assert_eq!(2, print_comparison(&current, &previous, 5.0));
+        assert_eq!(1, print_comparison(&current, &previous, 10.0));
+        assert_eq!(0, print_comparison(&current, &previous, 50.0));
+    }
+}
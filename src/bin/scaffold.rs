@@ -1,11 +1,16 @@
 use std::error::Error;
-use std::fs::File;
-use std::io::Write;
+use std::fs;
+use std::io::ErrorKind;
 use std::ops::Not;
+use std::path::Path;
 use std::str::FromStr;
-use std::{env, process};
+use std::{env, iter, process};
 
+use advent_of_code::client::{CONFIG_PATH, Client, ClientConfig};
+use advent_of_code::puzzle::Puzzle;
+use advent_of_code::solutions;
 use chrono::Datelike;
+use itertools::Itertools;
 
 /// Binary to scaffold code for a new Advent of Code day.
 ///
@@ -21,15 +26,54 @@ use chrono::Datelike;
 /// $ cargo run --bin scaffold -- 7
 /// ```
 ///
+/// Days whose input is parsed as a whole rather than line by line (taking a `&str`) are scaffolded with `--str`:
+/// ```shell
+/// $ cargo run --bin scaffold -- 7 --str
+/// ```
+///
+/// Once part 1 is solved, this adds a `solve_2` to "Day 07", along with its sample and solution tests:
+/// ```shell
+/// $ cargo run --bin scaffold -- part2 7
+/// ```
+///
+/// The problem file and part 1 sample can be filled in from the puzzle's page,
+/// either saved locally or fetched from the Advent of Code website:
+/// ```shell
+/// $ cargo run --bin scaffold -- 7 --html ~/Downloads/day_07.html
+/// $ cargo run --bin scaffold -- 7 --fetch
+/// ```
+///
+/// This will only print what scaffolding "Day 07" would change, without touching any file:
+/// ```shell
+/// $ cargo run --bin scaffold -- 7 --dry-run
+/// ```
+///
+/// Days are scaffolded for the most recent registered year, unless another one is passed with `--year`:
+/// ```shell
+/// $ cargo run --bin scaffold -- 7 --year 2024
+/// ```
+///
 /// Alternatively the binary can be built and called directly:
 /// ```shell
 /// $ cargo build --release
 /// $ ./target/release/scaffold 7
 /// ```
 ///
+/// Besides the (empty) input file, the problem file and the day's module under `src/y<year>`,
+/// this declares the module in `y<year>.rs` and registers the day in its `DAYS`,
+/// so it is runnable and benchmarked. Both are kept sorted, and skipped if already present.
+/// The day also gets a fuzz target under `fuzz/fuzz_targets`, registered in `fuzz/Cargo.toml`,
+/// with the part 1 sample (or an empty input without one) as its seed corpus under `fuzz/corpus`.
+/// The first day of a new year also creates `y<year>.rs`, declared in `lib.rs` and registered in `solutions::YEARS`.
+///
+/// All files are written at once: if any write fails, none of the files are changed.
+///
 /// Optionally an environment variable `OVERWRITE` can be set,
 /// this will overwrite contents of existing files,
 /// normally existing files would throw an error.
+///
+/// When fetching, the session (needed for the description of part 2) and the website's address
+/// are read from `./aoc.toml`, or the `AOC_SESSION` and `AOC_BASE_URL` environment variables.
 fn main() {
     let args: Vec<String> = env::args().collect();
 
@@ -44,69 +88,625 @@ fn main() {
     }
 }
 
+const LIB_PATH: &str = "./src/lib.rs";
+const SOLUTIONS_PATH: &str = "./src/solutions.rs";
+const FUZZ_MANIFEST_PATH: &str = "./fuzz/Cargo.toml";
+
 struct Config {
+    command: Command,
+    year: i32,
     day: u8,
+    puzzle: Option<PuzzleSource>,
     overwrite: bool,
+    dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Command {
+    New(InputKind),
+    Part2,
+}
+
+/// Where to import the puzzle's page from
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum PuzzleSource {
+    File(String),
+    Fetch,
+}
+
+/// How a day reads its input: line by line as `&[&str]`, or as a whole as `&str`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum InputKind {
+    Lines,
+    Str,
 }
 
 impl Config {
     fn build(args: &[String]) -> Result<Config, &'static str> {
-        let day = if args.len() >= 2 {
-            u8::from_str(&args[1])
-        } else {
-            Ok(chrono::prelude::Utc::now().day() as u8)
-        };
-        if day.is_err() {
-            return Err("invalid day");
+        let mut part_2 = false;
+        let mut input = InputKind::Lines;
+        let mut year = None;
+        let mut day = None;
+        let mut puzzle = None;
+        let mut dry_run = false;
+
+        let mut args = args[1..].iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "part2" => part_2 = true,
+                "--str" => input = InputKind::Str,
+                "--html" => {
+                    let path = args.next().ok_or("missing file after `--html`")?;
+                    puzzle = Some(PuzzleSource::File(path.clone()));
+                }
+                "--fetch" => puzzle = Some(PuzzleSource::Fetch),
+                "--dry-run" => dry_run = true,
+                "--year" => {
+                    let value = args.next().ok_or("missing year after `--year`")?;
+                    year = Some(i32::from_str(value).map_err(|_| "invalid year")?);
+                }
+                arg => day = Some(u8::from_str(arg).map_err(|_| "invalid day")?),
+            }
         }
-        let day = day.unwrap();
+        let year = year.unwrap_or_else(solutions::latest_year);
+        let day = day.unwrap_or_else(|| chrono::prelude::Utc::now().day() as u8);
+        let command = match (part_2, input, &puzzle) {
+            (false, input, _) => Command::New(input),
+            (true, InputKind::Lines, None) => Command::Part2,
+            (true, _, _) => return Err("`--str`, `--html` and `--fetch` only apply to new days"),
+        };
 
         let overwrite = env::var("OVERWRITE").is_ok();
 
-        Ok(Config { day, overwrite })
+        Ok(Config {
+            command,
+            year,
+            day,
+            puzzle,
+            overwrite,
+            dry_run,
+        })
     }
 }
 
+/// A file to write, along with its original content (if it already existed) to roll back to
+struct Change {
+    path: String,
+    content: String,
+    original: Option<String>,
+}
+
 fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let changes = plan(&config)?;
+
+    if config.dry_run {
+        changes.iter().for_each(print_change);
+        Ok(())
+    } else {
+        apply(&changes)
+    }
+}
+
+fn plan(config: &Config) -> Result<Vec<Change>, Box<dyn Error>> {
+    match config.command {
+        Command::New(input) => plan_new(config, input),
+        Command::Part2 => plan_part_2(config),
+    }
+}
+
+fn plan_new(config: &Config, input: InputKind) -> Result<Vec<Change>, Box<dyn Error>> {
+    let year = config.year;
     let day = format!("day_{:0>2}", config.day);
+    let template = match input {
+        InputKind::Lines => TEMPLATE_LINES,
+        InputKind::Str => TEMPLATE_STR,
+    };
 
-    write_file(&config, &format!("./inputs/{}.txt", day), "")?;
-    write_file(&config, &format!("./problems/{}.txt", day), "")?;
-    write_file(
-        &config,
-        &format!("./src/solutions/{}.rs", day),
-        &TEMPLATE.replace("{}", &day),
-    )?;
+    let puzzle = match &config.puzzle {
+        Some(PuzzleSource::File(path)) => Some(Puzzle::from_html(&fs::read_to_string(path)?)?),
+        Some(PuzzleSource::Fetch) => {
+            let client = Client::from_config(ClientConfig::load(CONFIG_PATH)?);
+            Some(Puzzle::from_html(
+                &client.puzzle_html(config.year, config.day)?,
+            )?)
+        }
+        None => None,
+    };
+    let (text, sample) = match puzzle {
+        Some(Puzzle { text, sample }) => (text, sample),
+        None => (String::new(), None),
+    };
+    let day_file = match &sample {
+        Some(sample) => with_sample(&fill(template, year, config.day), sample, input),
+        None => fill(template, year, config.day),
+    };
+    let fuzz_target = format!("y{year}_{day}");
 
-    append_file("./src/solutions.rs", &format!("pub mod {};\n", day))?;
+    let mut changes = vec![
+        new_file(config, &format!("./inputs/{year}/{day}.txt"), String::new())?,
+        new_file(config, &format!("./problems/{year}/{day}.txt"), text)?,
+        new_file(config, &format!("./src/y{year}/{day}.rs"), day_file)?,
+        new_file(
+            config,
+            &format!("./fuzz/fuzz_targets/{fuzz_target}.rs"),
+            fill(TEMPLATE_FUZZ_TARGET, year, config.day),
+        )?,
+        new_file(
+            config,
+            &format!("./fuzz/corpus/{fuzz_target}/sample_1"),
+            sample.unwrap_or_default(),
+        )?,
+    ];
+    changes.extend(update(FUZZ_MANIFEST_PATH, |manifest| {
+        register_fuzz_target(manifest, &fuzz_target)
+    })?);
 
-    Ok(())
+    let year_path = format!("./src/y{year}.rs");
+    match fs::read_to_string(&year_path) {
+        Ok(_) => changes.extend(update(&year_path, |days| register_day(days, config.day))?),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            let days = fill(TEMPLATE_YEAR, year, config.day);
+            changes.push(new_file(config, &year_path, days)?);
+            changes.extend(update(LIB_PATH, |lib| declare_year(lib, year))?);
+            changes.extend(update(SOLUTIONS_PATH, |years| register_year(years, year))?);
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(changes)
+}
+
+fn plan_part_2(config: &Config) -> Result<Vec<Change>, Box<dyn Error>> {
+    let path = format!("./src/y{}/day_{:0>2}.rs", config.year, config.day);
+    let original = fs::read_to_string(&path)?;
+
+    Ok(vec![Change {
+        path,
+        content: promote(&original, config.year, config.day)?,
+        original: Some(original),
+    }])
+}
+
+/// Edits the existing file at `path`, a change only if that actually changed anything
+fn update(
+    path: &str,
+    edit: impl Fn(&str) -> Result<String, Box<dyn Error>>,
+) -> Result<Option<Change>, Box<dyn Error>> {
+    let original = fs::read_to_string(path)?;
+    let content = edit(&original)?;
+
+    Ok((content != original).then(|| Change {
+        path: path.to_string(),
+        content,
+        original: Some(original),
+    }))
+}
+
+fn new_file(config: &Config, path: &str, content: String) -> Result<Change, Box<dyn Error>> {
+    let original = match fs::read_to_string(path) {
+        Ok(original) if config.overwrite => Some(original),
+        Ok(_) => return Err(format!("{path} already exists, set `OVERWRITE` to overwrite").into()),
+        Err(e) if e.kind() == ErrorKind::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(Change {
+        path: path.to_string(),
+        content,
+        original,
+    })
 }
 
-fn write_file(config: &Config, path: &str, content: &str) -> Result<(), Box<dyn Error>> {
-    File::options()
-        .create_new(config.overwrite.not())
-        .write(true)
-        .truncate(true)
-        .open(path)?
-        .write_all(content.as_ref())?;
+fn print_change(change: &Change) {
+    let Some(original) = &change.original else {
+        println!("create {}", change.path);
+        return;
+    };
+
+    println!("update {}", change.path);
+    original
+        .lines()
+        .filter(|line| change.content.lines().contains(line).not())
+        .for_each(|line| println!("  - {}", line.trim()));
+    change
+        .content
+        .lines()
+        .filter(|line| original.lines().contains(line).not())
+        .for_each(|line| println!("  + {}", line.trim()));
+}
+
+/// Writes all changes, or none of them.
+///
+/// Every change is first staged in a temporary file next to its target,
+/// only once all of them are staged they are renamed into place.
+/// If that fails halfway, the already renamed files are restored to their original content.
+fn apply(changes: &[Change]) -> Result<(), Box<dyn Error>> {
+    let mut staged = vec![];
+
+    for change in changes {
+        let temp_path = format!("{}.tmp", change.path);
+        let write = Path::new(&change.path)
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::write(&temp_path, &change.content));
+
+        if let Err(e) = write {
+            staged.push(temp_path);
+            remove_files(&staged);
+            return Err(e.into());
+        }
+        staged.push(temp_path);
+    }
+
+    for (idx, (change, temp_path)) in changes.iter().zip(&staged).enumerate() {
+        if let Err(e) = fs::rename(temp_path, &change.path) {
+            rollback(&changes[..idx]);
+            remove_files(&staged[idx..]);
+            return Err(e.into());
+        }
+    }
 
     Ok(())
 }
 
-fn append_file(path: &str, content: &str) -> Result<(), Box<dyn Error>> {
-    File::options()
-        .write(true)
-        .append(true)
-        .open(path)?
-        .write_all(content.as_ref())?;
+fn rollback(changes: &[Change]) {
+    for change in changes {
+        let _ = match &change.original {
+            Some(original) => fs::write(&change.path, original),
+            None => fs::remove_file(&change.path),
+        };
+    }
+}
+
+fn remove_files(paths: &[String]) {
+    for path in paths {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// Declares the day's module in its year's `y<year>.rs` and adds it to the `DAYS` registry,
+/// each in sorted position among the existing days, and only if not present yet
+fn register_day(solutions: &str, day: u8) -> Result<String, Box<dyn Error>> {
+    let module = format!("pub mod day_{day:0>2};");
+    let entry = format!("    ({day}, &day_{day:0>2}::Day{day:0>2}),");
+    let mut lines = solutions.lines().map(str::to_string).collect_vec();
+
+    insert_sorted(&mut lines, day, &module, |line| {
+        line.strip_prefix("pub mod day_")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })
+    .ok_or("no `pub mod day_XX;` declarations found")?;
+    insert_sorted(&mut lines, day, &entry, |line| {
+        line.trim()
+            .strip_prefix('(')?
+            .split_once(", &day_")?
+            .0
+            .parse()
+            .ok()
+    })
+    .ok_or("no `DAYS` registry entries found")?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Declares a new year's module in `lib.rs`, in sorted position among the existing years
+fn declare_year(lib: &str, year: i32) -> Result<String, Box<dyn Error>> {
+    let module = format!("pub mod y{year};");
+    let mut lines = lib.lines().map(str::to_string).collect_vec();
+
+    insert_sorted(&mut lines, year, &module, |line| {
+        line.strip_prefix("pub mod y")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })
+    .ok_or("no `pub mod yXXXX;` declarations found")?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a new year's `DAYS` to the `YEARS` registry in `solutions.rs`, keeping it sorted by year
+fn register_year(solutions: &str, year: i32) -> Result<String, Box<dyn Error>> {
+    let declaration = "pub static YEARS: &[(i32, Days)] = &[";
+    let (start, rest) = solutions
+        .split_once(declaration)
+        .ok_or("no `YEARS` registry found")?;
+    let (entries, end) = rest.split_once("];").ok_or("`YEARS` is never closed")?;
+
+    let mut years = entries
+        .split('(')
+        .skip(1)
+        .map(|entry| {
+            entry
+                .split_once(',')
+                .map(|(year, _)| year.trim().parse::<i32>())
+        })
+        .collect::<Option<Result<Vec<_>, _>>>()
+        .ok_or("`YEARS` has an entry without a year")??;
+    if years.contains(&year) {
+        return Ok(solutions.to_string());
+    }
+    years.push(year);
+    years.sort();
+
+    let entries = years
+        .iter()
+        .map(|year| format!("({year}, crate::y{year}::DAYS)"))
+        .join(", ");
+    Ok(format!("{start}{declaration}{entries}];{end}"))
+}
+
+/// Adds the `[[bin]]` of a day's fuzz target to the fuzz crate's `Cargo.toml`,
+/// sorted among the existing targets by name, and only if not present yet
+fn register_fuzz_target(manifest: &str, name: &str) -> Result<String, Box<dyn Error>> {
+    let header = "[[bin]]\nname = \"";
+    if manifest.contains(&format!("{header}{name}\"")) {
+        return Ok(manifest.to_string());
+    }
+    let target = format!(
+        "{header}{name}\"\npath = \"fuzz_targets/{name}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+    );
+
+    let targets = manifest
+        .match_indices(header)
+        .map(|(idx, _)| (idx, manifest[idx + header.len()..].split('"').next()))
+        .collect_vec();
+    let (last, _) = targets.last().ok_or("no `[[bin]]` fuzz targets found")?;
+
+    Ok(
+        match targets.iter().find(|&&(_, other)| other > Some(name)) {
+            Some(&(idx, _)) => format!("{}{target}\n{}", &manifest[..idx], &manifest[idx..]),
+            None if manifest[*last..].ends_with('\n') => format!("{manifest}\n{target}"),
+            None => format!("{manifest}\n\n{target}"),
+        },
+    )
+}
+
+/// Inserts `line` among the existing lines of the same kind (those `key_of` returns a key for),
+/// keeping those sorted by key. Returns `None` when there are no lines of that kind to go by.
+fn insert_sorted<K: Copy + Ord>(
+    lines: &mut Vec<String>,
+    key: K,
+    line: &str,
+    key_of: impl Fn(&str) -> Option<K>,
+) -> Option<()> {
+    let existing = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| key_of(line).map(|key| (idx, key)))
+        .collect_vec();
+
+    if existing
+        .iter()
+        .any(|&(_, existing_key)| existing_key == key)
+    {
+        return Some(());
+    }
+
+    let idx = existing
+        .iter()
+        .find(|&&(_, existing_key)| existing_key > key)
+        .map(|&(idx, _)| idx)
+        .or_else(|| existing.last().map(|&(idx, _)| idx + 1))?;
+    lines.insert(idx, line.to_string());
+
+    Some(())
+}
+
+/// Fills in the day's placeholders of a template: `{}` for `day_XX`, `{name}` for `DayXX`,
+/// `{nr}` for the plain day number and `{year}` for its year
+fn fill(template: &str, year: i32, day: u8) -> String {
+    template
+        .replace("{}", &format!("day_{day:0>2}"))
+        .replace("{name}", &format!("Day{day:0>2}"))
+        .replace("{nr}", &day.to_string())
+        .replace("{year}", &year.to_string())
+}
+
+/// Replaces the placeholder `const SAMPLE` of a freshly filled in template by `sample`
+fn with_sample(day_file: &str, sample: &str, input: InputKind) -> String {
+    let escape = |line: &str| line.replace('\\', "\\\\").replace('"', "\\\"");
+    let sample = match input {
+        InputKind::Lines => iter::once("    const SAMPLE: &[&str] = &[".to_string())
+            .chain(
+                sample
+                    .lines()
+                    .map(|line| format!("        \"{}\",", escape(line))),
+            )
+            .chain(iter::once("    ];".to_string()))
+            .join("\n"),
+        InputKind::Str => {
+            // A line continuation skips leading whitespace, so a leading space has to be escaped
+            let lines = sample
+                .lines()
+                .map(|line| match line.strip_prefix(' ') {
+                    Some(line) => format!("        \\x20{}", escape(line)),
+                    None => format!("        {}", escape(line)),
+                })
+                .join("\\n\\\n");
+            format!("    const SAMPLE: &str = \"\\\n{lines}\\\n    \";")
+        }
+    };
+
+    let mut lines = day_file.lines().map(str::to_string).collect_vec();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("    const SAMPLE"))
+        .unwrap();
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.ends_with(';'))
+            .unwrap();
+    lines.splice(start..=end, iter::once(sample));
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds part 2 to a day scaffolded with only part 1: a `solve_2` stub, wired into the `Solution`,
+/// and its sample and solution tests.
+///
+/// A sample still declared inside the part 1 test is moved to a `const SAMPLE` shared by both tests.
+fn promote(day_file: &str, year: i32, day: u8) -> Result<String, Box<dyn Error>> {
+    if day_file.contains("pub fn solve_2(") {
+        return Err(format!("day {day:0>2} already has a `solve_2`").into());
+    }
+    if day_file.contains(PART_2_STUB).not() {
+        return Err("`part_2` no longer is the scaffolded stub, cannot wire in `solve_2`".into());
+    }
+
+    let input = if day_file.contains("type Input<'a> = &'a str;") {
+        InputKind::Str
+    } else {
+        InputKind::Lines
+    };
+    let (solve_2, tests) = match input {
+        InputKind::Lines => (SOLVE_2_LINES, TESTS_2_LINES),
+        InputKind::Str => (SOLVE_2_STR, TESTS_2_STR),
+    };
+    let param = day_file
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("fn part_1(")?.split_once(':'))
+        .map(|(param, _)| param)
+        .filter(|&param| param != "_")
+        .unwrap_or("input");
+
+    let day_file = day_file.replace(PART_2_STUB, &PART_2.replace("{param}", param));
+    let day_file = day_file.replace("type Answer2 = ();", "type Answer2 = u32;");
+    let mut lines = day_file.lines().map(str::to_string).collect_vec();
+
+    let solve_1 = lines
+        .iter()
+        .position(|line| line.starts_with("pub fn solve_1("))
+        .ok_or("no `pub fn solve_1` found")?;
+    let solve_1_end = solve_1
+        + lines[solve_1..]
+            .iter()
+            .position(|line| line == "}")
+            .ok_or("`solve_1` is never closed")?;
+    lines.splice(
+        solve_1_end + 1..solve_1_end + 1,
+        solve_2.lines().map(str::to_string),
+    );
+
+    share_sample(&mut lines)?;
+
+    let tests_end = lines
+        .iter()
+        .rposition(|line| line == "}")
+        .ok_or("no `tests` module found")?;
+    lines.splice(
+        tests_end..tests_end,
+        fill(tests, year, day).lines().map(str::to_string),
+    );
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Moves a `let sample = ...;` out of the tests into a `const SAMPLE` at the top of the `tests` module,
+/// unless there already is one
+fn share_sample(lines: &mut Vec<String>) -> Result<(), Box<dyn Error>> {
+    if lines
+        .iter()
+        .any(|line| line.trim_start().starts_with("const SAMPLE"))
+    {
+        return Ok(());
+    }
+
+    let tests = lines
+        .iter()
+        .position(|line| line == "mod tests {")
+        .ok_or("no `tests` module found")?;
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("let sample = "))
+        .ok_or("no `const SAMPLE` or `let sample` found")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.ends_with(';'))
+            .ok_or("`let sample` is never closed")?;
+
+    let mut sample = lines
+        .drain(start..=end)
+        .map(|line| line.strip_prefix("    ").unwrap_or(&line).to_string())
+        .collect_vec();
+    sample[0] = sample[0]
+        .replace("let sample = vec![", "const SAMPLE: &[&str] = &[")
+        .replace("let sample = ", "const SAMPLE: &str = ");
+    if lines[start].is_empty() {
+        lines.remove(start);
+    }
+
+    lines[tests..].iter_mut().for_each(|line| {
+        *line = line
+            .replace("(&sample", "(SAMPLE")
+            .replace("(sample", "(SAMPLE");
+    });
+
+    let uses_end = tests
+        + lines[tests..]
+            .iter()
+            .rposition(|line| line.starts_with("    use "))
+            .ok_or("no `use` in the `tests` module")?;
+    lines.splice(
+        uses_end + 1..uses_end + 1,
+        iter::once(String::new()).chain(sample),
+    );
 
     Ok(())
 }
 
-const TEMPLATE: &str = "\
-pub fn solve_1(_: &[&str]) -> u32 {
-    42
+const TEMPLATE_FUZZ_TARGET: &str = "\
+#![no_main]
+
+use advent_of_code::fuzz;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = fuzz::solve({year}, {nr}, data);
+});
+";
+
+const TEMPLATE_YEAR: &str = "\
+use crate::solutions::Days;
+
+pub mod {};
+
+/// Registry of all solved days of {year}, ordered by day number.
+pub static DAYS: Days = &[
+    ({nr}, &{}::{name}),
+];
+";
+
+const TEMPLATE_LINES: &str = "\
+use crate::parse::ParseError;
+use crate::solutions::Solution;
+
+pub fn solve_1(_: &[&str]) -> Result<u32, ParseError> {
+    Ok(42)
+}
+
+pub struct {name};
+
+impl Solution for {name} {
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Result<u32, ParseError> {
+        solve_1(lines)
+    }
+
+    fn part_2(_: &Self::Input<'_>) -> Result<(), ParseError> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -114,32 +714,319 @@ mod tests {
     use itertools::Itertools;
 
     use super::*;
+    use crate::answers;
+
+    const SAMPLE: &[&str] = &[
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+        \"\",
+    ];
 
     #[test]
     fn {}_part_01_sample() {
-        let sample = vec![
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-            \"\",
-        ];
+        assert_eq!(Ok(42), solve_1(SAMPLE));
+    }
+
+    #[test]
+    fn {}_part_01_solution() {
+        let Some((input, answer)) = answers::expected({year}, {nr}, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
 
-        assert_eq!(42, solve_1(&sample));
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+}
+";
+
+const TEMPLATE_STR: &str = "\
+use crate::parse::ParseError;
+use crate::solutions::Solution;
+
+pub fn solve_1(_: &str) -> Result<u32, ParseError> {
+    Ok(42)
+}
+
+pub struct {name};
+
+impl Solution for {name} {
+    type Input<'a> = &'a str;
+    type Answer1 = u32;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.trim())
+    }
+
+    fn part_1(input: &Self::Input<'_>) -> Result<u32, ParseError> {
+        solve_1(input)
+    }
+
+    fn part_2(_: &Self::Input<'_>) -> Result<(), ParseError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    const SAMPLE: &str = \"\\
+    \";
+
+    #[test]
+    fn {}_part_01_sample() {
+        assert_eq!(Ok(42), solve_1(SAMPLE));
     }
 
     #[test]
     fn {}_part_01_solution() {
-        let input = include_str!(\"../../inputs/{}.txt\")
-            .lines()
-            .collect_vec();
+        let Some((input, answer)) = answers::expected({year}, {nr}, 1) else {
+            return;
+        };
 
-        assert_eq!(0, solve_1(&input));
+        assert_eq!(Ok(answer), solve_1(input.trim()));
     }
 }
 ";
+
+/// The `part_2` of both templates, replaced when promoting a day to part 2
+const PART_2_STUB: &str = "    fn part_2(_: &Self::Input<'_>) -> Result<(), ParseError> {
+        Ok(())
+    }
+";
+
+const PART_2: &str = "    fn part_2({param}: &Self::Input<'_>) -> Result<u32, ParseError> {
+        solve_2({param})
+    }
+";
+
+const SOLVE_2_LINES: &str = "
+pub fn solve_2(_: &[&str]) -> Result<u32, ParseError> {
+    Ok(42)
+}";
+
+const SOLVE_2_STR: &str = "
+pub fn solve_2(_: &str) -> Result<u32, ParseError> {
+    Ok(42)
+}";
+
+const TESTS_2_LINES: &str = "
+    #[test]
+    fn {}_part_02_sample() {
+        assert_eq!(Ok(42), solve_2(SAMPLE));
+    }
+
+    #[test]
+    fn {}_part_02_solution() {
+        let Some((input, answer)) = answers::expected({year}, {nr}, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }";
+
+const TESTS_2_STR: &str = "
+    #[test]
+    fn {}_part_02_sample() {
+        assert_eq!(Ok(42), solve_2(SAMPLE));
+    }
+
+    #[test]
+    fn {}_part_02_solution() {
+        let Some((input, answer)) = answers::expected({year}, {nr}, 2) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_2(input.trim()));
+    }";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "\
+pub mod day_01;
+pub mod day_03;
+
+pub static DAYS: Days = &[
+    (1, &day_01::Day01),
+    (3, &day_03::Day03),
+];
+";
+
+    #[test]
+    fn register_day_inserts_sorted() {
+        assert_eq!(
+            "\
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+
+pub static DAYS: Days = &[
+    (1, &day_01::Day01),
+    (2, &day_02::Day02),
+    (3, &day_03::Day03),
+];
+",
+            register_day(DAYS, 2).unwrap()
+        );
+        assert_eq!(
+            "\
+pub mod day_01;
+pub mod day_03;
+pub mod day_04;
+
+pub static DAYS: Days = &[
+    (1, &day_01::Day01),
+    (3, &day_03::Day03),
+    (4, &day_04::Day04),
+];
+",
+            register_day(DAYS, 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn register_day_is_idempotent() {
+        assert_eq!(DAYS, register_day(DAYS, 3).unwrap());
+        assert!(register_day("", 3).is_err());
+    }
+
+    #[test]
+    fn new_year_is_declared_and_registered() {
+        let lib = "pub mod parse;\npub mod y2023;\npub mod y2025;\n";
+        let solutions = "pub static YEARS: &[(i32, Days)] = &[(2023, crate::y2023::DAYS), (2025, crate::y2025::DAYS)];\n";
+
+        assert_eq!(
+            "pub mod parse;\npub mod y2023;\npub mod y2024;\npub mod y2025;\n",
+            declare_year(lib, 2024).unwrap()
+        );
+        assert_eq!(
+            "pub static YEARS: &[(i32, Days)] = &[(2023, crate::y2023::DAYS), (2024, crate::y2024::DAYS), (2025, crate::y2025::DAYS)];\n",
+            register_year(solutions, 2024).unwrap()
+        );
+        assert_eq!(solutions, register_year(solutions, 2025).unwrap());
+        assert!(register_year("", 2024).is_err());
+        assert!(fill(TEMPLATE_YEAR, 2024, 3).contains("    (3, &day_03::Day03),\n"));
+    }
+
+    #[test]
+    fn fuzz_target_is_registered_sorted() {
+        let target = |name: &str| {
+            format!(
+                "[[bin]]\nname = \"{name}\"\npath = \"fuzz_targets/{name}.rs\"\ntest = false\ndoc = false\nbench = false\n"
+            )
+        };
+        let manifest = format!(
+            "[workspace]\nmembers = [\".\"]\n\n{}\n{}",
+            target("y2024_day_01"),
+            target("y2025_day_01")
+        );
+
+        assert_eq!(
+            format!(
+                "[workspace]\nmembers = [\".\"]\n\n{}\n{}\n{}",
+                target("y2024_day_01"),
+                target("y2024_day_02"),
+                target("y2025_day_01")
+            ),
+            register_fuzz_target(&manifest, "y2024_day_02").unwrap()
+        );
+        assert_eq!(
+            format!("{manifest}\n{}", target("y2025_day_03")),
+            register_fuzz_target(&manifest, "y2025_day_03").unwrap()
+        );
+        assert_eq!(
+            manifest,
+            register_fuzz_target(&manifest, "y2025_day_01").unwrap()
+        );
+        assert!(register_fuzz_target("[workspace]\n", "y2025_day_01").is_err());
+        assert_eq!(
+            fs::read_to_string("./fuzz/fuzz_targets/y2025_day_01.rs").unwrap(),
+            fill(TEMPLATE_FUZZ_TARGET, 2025, 1)
+        );
+    }
+
+    #[test]
+    fn promote_adds_part_2() {
+        let promoted = promote(&fill(TEMPLATE_LINES, 2025, 7), 2025, 7).unwrap();
+
+        assert!(promoted.contains("pub fn solve_2(_: &[&str]) -> Result<u32, ParseError> {"));
+        assert!(promoted.contains("type Answer2 = u32;"));
+        assert!(promoted.contains("        solve_2(lines)\n"));
+        assert!(promoted.contains("fn day_07_part_02_sample() {"));
+        assert!(promoted.contains("answers::expected(2025, 7, 2)"));
+        assert!(promoted.ends_with("solve_2(&input));\n    }\n}\n"));
+        assert!(promote(&promoted, 2025, 7).is_err());
+
+        let promoted = promote(&fill(TEMPLATE_STR, 2025, 7), 2025, 7).unwrap();
+
+        assert!(promoted.contains("pub fn solve_2(_: &str) -> Result<u32, ParseError> {"));
+        assert!(promoted.contains("        solve_2(input)\n"));
+        assert!(promoted.contains("solve_2(input.trim())"));
+    }
+
+    #[test]
+    fn promote_shares_sample() {
+        let day_file = fill(TEMPLATE_LINES, 2025, 7);
+        let tests = day_file.find("    const SAMPLE").unwrap();
+        let day_file = day_file[..tests].to_string()
+            + "    #[test]
+    fn day_07_part_01_sample() {
+        let sample = vec![
+            \"L68\",
+            \"L30\",
+        ];
+
+        assert_eq!(Ok(42), solve_1(&sample));
+    }
+}
+";
+
+        let promoted = promote(&day_file, 2025, 7).unwrap();
+
+        assert!(promoted.contains(
+            "    use crate::answers;
+
+    const SAMPLE: &[&str] = &[
+        \"L68\",
+        \"L30\",
+    ];
+
+    #[test]
+    fn day_07_part_01_sample() {
+        assert_eq!(Ok(42), solve_1(SAMPLE));
+    }
+"
+        ));
+    }
+
+    #[test]
+    fn with_sample_replaces_placeholder() {
+        let day_file = with_sample(
+            &fill(TEMPLATE_LINES, 2025, 7),
+            "L68\nR\"1\"\n",
+            InputKind::Lines,
+        );
+
+        assert!(day_file.contains(
+            "    const SAMPLE: &[&str] = &[\n        \"L68\",\n        \"R\\\"1\\\"\",\n    ];\n\n"
+        ));
+
+        let day_file = with_sample(&fill(TEMPLATE_STR, 2025, 7), "3-5\n 10\n", InputKind::Str);
+
+        assert!(day_file.contains(
+            "    const SAMPLE: &str = \"\\\n        3-5\\n\\\n        \\x2010\\\n    \";\n\n"
+        ));
+    }
+}
@@ -0,0 +1,405 @@
+use crate::answer::Answer;
+use crate::puzzle;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io, thread};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const CONFIG_PATH: &str = "./aoc.toml";
+pub const RATE_LIMIT_PATH: &str = "./.aoc_last_request";
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Identifies this repository to the Advent of Code servers, as their automation guidelines ask
+pub const USER_AGENT: &str = "github.com/bram-inniger/advent-of-code-2025-rust";
+
+static WAIT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"You have (?:(?<minutes>\d+)m )?(?<seconds>\d+)s left to wait").unwrap()
+});
+
+/// Settings for talking to the Advent of Code website, stored in `aoc.toml` as
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "http://localhost:8080"
+/// ```
+///
+/// Both are optional, and overridden by the environment variables `AOC_SESSION` and `AOC_BASE_URL`.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize)]
+pub struct ClientConfig {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl ClientConfig {
+    /// Loads the config at `path`, a missing file simply leaves everything to the environment
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(config) => toml::from_str(&config)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Client for the Advent of Code website, refusing to request puzzles that are not unlocked yet
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    rate_limit: Option<RateLimit>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            rate_limit: None,
+        }
+    }
+
+    /// Client as configured by `config`, rate limited across runs through [`RATE_LIMIT_PATH`]
+    pub fn from_config(config: ClientConfig) -> Self {
+        let base_url = config.base_url.as_deref().unwrap_or(BASE_URL);
+        Self::new(base_url, config.session)
+            .with_rate_limit(RateLimit::new(RATE_LIMIT_PATH, MIN_REQUEST_INTERVAL))
+    }
+
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// The HTML page of `day`'s puzzle of `year`, without a session only the description of part 1 is served
+    pub fn puzzle_html(&self, year: i32, day: u8) -> Result<String, Box<dyn Error>> {
+        self.get(year, day, &format!("/{year}/day/{day}"))
+    }
+
+    /// The personal input of `day` of `year`, which needs a session
+    pub fn input(&self, year: i32, day: u8) -> Result<String, Box<dyn Error>> {
+        if self.session.is_none() {
+            return Err(
+                "fetching an input needs a session, set `AOC_SESSION` or `session` in aoc.toml"
+                    .into(),
+            );
+        }
+
+        self.get(year, day, &format!("/{year}/day/{day}/input"))
+    }
+
+    /// Submits `answer` for `part` of `day` of `year`, which needs a session
+    pub fn submit(
+        &self,
+        year: i32,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Outcome, Box<dyn Error>> {
+        if self.session.is_none() {
+            return Err(
+                "submitting an answer needs a session, set `AOC_SESSION` or `session` in aoc.toml"
+                    .into(),
+            );
+        }
+        self.prepare(year, day)?;
+
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let request = self.with_headers(ureq::post(&url));
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let html = request.send_form(form)?.body_mut().read_to_string()?;
+
+        Outcome::from_html(&html)
+    }
+
+    fn get(&self, year: i32, day: u8, path: &str) -> Result<String, Box<dyn Error>> {
+        self.prepare(year, day)?;
+
+        let url = format!("{}{path}", self.base_url);
+        let request = self.with_headers(ureq::get(&url));
+
+        Ok(request.call()?.body_mut().read_to_string()?)
+    }
+
+    /// Refuses days that are not unlocked yet, and waits for the rate limit
+    fn prepare(&self, year: i32, day: u8) -> Result<(), Box<dyn Error>> {
+        ensure_unlocked(year, day, Utc::now())?;
+        if let Some(rate_limit) = &self.rate_limit {
+            rate_limit.wait()?;
+        }
+
+        Ok(())
+    }
+
+    fn with_headers<B>(&self, request: ureq::RequestBuilder<B>) -> ureq::RequestBuilder<B> {
+        let request = request.header("User-Agent", USER_AGENT);
+        match &self.session {
+            Some(session) => request.header("Cookie", &format!("session={session}")),
+            None => request,
+        }
+    }
+}
+
+/// Refuses `day` of `year` if its puzzle is not unlocked yet at `now`
+fn ensure_unlocked(year: i32, day: u8, now: DateTime<Utc>) -> Result<(), String> {
+    let unlock_time = puzzle::unlock_time(year, day)?;
+    if now < unlock_time {
+        return Err(format!(
+            "day {day} is not unlocked yet, it unlocks at {unlock_time}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// The website's response to a submitted answer
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Outcome {
+    Verdict(Verdict),
+    /// Answered too recently, the next answer is only accepted after waiting this long
+    Wait(Duration),
+    /// The part was already solved, or part 1 is not solved yet
+    WrongLevel,
+}
+
+/// Judgement of a submitted answer
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    pub fn from_html(html: &str) -> Result<Self, Box<dyn Error>> {
+        if html.contains("That's the right answer") {
+            Ok(Self::Verdict(Verdict::Correct))
+        } else if html.contains("That's not the right answer") {
+            Ok(Self::Verdict(if html.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if html.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }))
+        } else if let Some(wait) = WAIT.captures(html) {
+            let minutes = wait.name("minutes").map_or(Ok(0), |m| m.as_str().parse())?;
+            let seconds: u64 = wait["seconds"].parse()?;
+            Ok(Self::Wait(Duration::from_secs(minutes * 60 + seconds)))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Ok(Self::WrongLevel)
+        } else {
+            Err("unrecognised response to the submitted answer".into())
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+        };
+        write!(f, "{verdict}")
+    }
+}
+
+/// Spaces out requests by at least `interval`, also across runs,
+/// by keeping the time of the last request in the file at `path`
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    path: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimit {
+    pub fn new(path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            interval,
+        }
+    }
+
+    /// Sleeps until the next request is allowed, and records it as the last one
+    pub fn wait(&self) -> io::Result<()> {
+        let last_request = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request {
+            let elapsed = last_request.elapsed().unwrap_or_default();
+            thread::sleep(self.interval.saturating_sub(elapsed));
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::write(&self.path, now.as_millis().to_string())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+    use std::time::Instant;
+
+    /// Serves a single request with `body`, returning the server's address,
+    /// and a handle to the received request line and headers followed by its body
+    pub(crate) fn serve_once(body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                match line.trim_end() {
+                    "" => break,
+                    line => request.push(line.to_string()),
+                }
+            }
+            let content_length = request
+                .iter()
+                .find_map(|header| header.strip_prefix("content-length: "))
+                .map_or(0, |length| length.parse().unwrap());
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push(String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, server)
+    }
+
+    #[test]
+    fn client_fetches_puzzle_and_input() {
+        let (base_url, server) = serve_once("<html></html>");
+        let client = Client::new(&base_url, None);
+
+        assert_eq!("<html></html>", client.puzzle_html(2025, 1).unwrap());
+        assert_eq!("GET /2025/day/1 HTTP/1.1", server.join().unwrap()[0]);
+        assert!(client.input(2025, 1).is_err());
+
+        let (base_url, server) = serve_once("L68\nL30\n");
+        let client = Client::new(&base_url, Some("abc".to_string()));
+
+        assert_eq!("L68\nL30\n", client.input(2025, 1).unwrap());
+        let request = server.join().unwrap();
+        assert_eq!("GET /2025/day/1/input HTTP/1.1", request[0]);
+        assert!(request.contains(&"cookie: session=abc".to_string()));
+    }
+
+    #[test]
+    fn client_submits_answer() {
+        let (base_url, server) = serve_once(
+            "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        );
+        let client = Client::new(&base_url, Some("abc".to_string()));
+
+        assert_eq!(
+            Outcome::Verdict(Verdict::TooHigh),
+            client.submit(2025, 1, 2, &Answer::Unsigned(6695)).unwrap()
+        );
+        let request = server.join().unwrap();
+        assert_eq!("POST /2025/day/1/answer HTTP/1.1", request[0]);
+        assert!(request.contains(&"cookie: session=abc".to_string()));
+        assert_eq!("level=2&answer=6695", request.last().unwrap());
+    }
+
+    #[test]
+    fn outcome_from_html() {
+        let outcome = |html| Outcome::from_html(html).unwrap();
+
+        assert_eq!(
+            Outcome::Verdict(Verdict::Correct),
+            outcome("<p>That's the right answer! You are one gold star closer.</p>")
+        );
+        assert_eq!(
+            Outcome::Verdict(Verdict::Wrong),
+            outcome("<p>That's not the right answer. Please wait one minute.</p>")
+        );
+        assert_eq!(
+            Outcome::Verdict(Verdict::TooLow),
+            outcome("<p>That's not the right answer; your answer is too low.</p>")
+        );
+        assert_eq!(
+            Outcome::Wait(Duration::from_secs(95)),
+            outcome("<p>You gave an answer too recently. You have 1m 35s left to wait.</p>")
+        );
+        assert_eq!(
+            Outcome::Wait(Duration::from_secs(12)),
+            outcome("<p>You gave an answer too recently. You have 12s left to wait.</p>")
+        );
+        assert_eq!(
+            Outcome::WrongLevel,
+            outcome("<p>You don't seem to be solving the right level.</p>")
+        );
+        assert!(Outcome::from_html("<html></html>").is_err());
+    }
+
+    #[test]
+    fn client_refuses_locked_days() {
+        let client = Client::new("http://127.0.0.1:1", Some("abc".to_string()));
+
+        assert!(client.input(2025, 0).is_err());
+        assert!(client.input(2025, 13).is_err());
+        let error = client.input(2099, 1).unwrap_err().to_string();
+        assert!(error.starts_with("day 1 is not unlocked yet"), "{error}");
+    }
+
+    #[test]
+    fn days_unlock_at_midnight_eastern() {
+        let unlock_time = puzzle::unlock_time(2025, 3).unwrap().with_timezone(&Utc);
+
+        assert_eq!(Ok(()), ensure_unlocked(2025, 3, unlock_time));
+        assert_eq!(
+            Err("day 3 is not unlocked yet, it unlocks at 2025-12-03 00:00:00 -05:00".to_string()),
+            ensure_unlocked(2025, 3, unlock_time - chrono::Duration::seconds(1))
+        );
+        assert_eq!("2025-12-03 05:00:00 UTC", unlock_time.to_string());
+    }
+
+    #[test]
+    fn rate_limit_spaces_out_requests() {
+        let path = env::temp_dir().join(format!("aoc_rate_limit_{}", std::process::id()));
+        let rate_limit = RateLimit::new(&path, Duration::from_millis(200));
+
+        let start = Instant::now();
+        rate_limit.wait().unwrap();
+        rate_limit.wait().unwrap();
+        fs::remove_file(path).unwrap();
+
+        // The last request is only recorded to the millisecond
+        assert!(start.elapsed() >= Duration::from_millis(199));
+    }
+}
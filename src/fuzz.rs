@@ -0,0 +1,132 @@
+use crate::generate::Rng;
+use crate::parse::ParseError;
+use crate::solutions;
+use rand::RngExt;
+use rand::seq::IndexedRandom;
+
+/// Characters the mutations insert, those that carry meaning in any day's input along with some that never do
+const ALPHABET: &[char] = &[
+    '0', '1', '5', '9', '\n', ' ', ',', '-', ':', 'x', '.', '#', '@', '^', 'S', 'L', 'R', '(', ')',
+    '[', ']', '{', '}', '+', '*', '\t', 'é', '🎄',
+];
+
+/// Parses arbitrary bytes as the input of the given day, as the fuzz targets under `./fuzz` do before solving it:
+/// this returns either `Ok` or a [`ParseError`], a panic is a bug in the parser.
+/// Bytes that are not valid UTF-8 are ignored, inputs are always read as text.
+///
+/// # Panics
+///
+/// If the day is not registered.
+pub fn parse(year: i32, day: u8, data: &[u8]) -> Result<(), ParseError> {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return Ok(());
+    };
+    let solution = solutions::day(year, day)
+        .unwrap_or_else(|| panic!("day {day} of {year} is not registered"));
+
+    solution.parse(input)
+}
+
+/// Like [`parse`], then also solves both parts of the day on any input that parses,
+/// as some input only turns out to be unsolvable once solved: that too must be an `Err` rather than a panic
+///
+/// # Panics
+///
+/// If the day is not registered.
+pub fn solve(year: i32, day: u8, data: &[u8]) -> Result<(), ParseError> {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return Ok(());
+    };
+    let solution = solutions::day(year, day)
+        .unwrap_or_else(|| panic!("day {day} of {year} is not registered"));
+
+    let mut answers = Ok(());
+    solution.with_parsed(input, &mut |solve| {
+        let (part_1, part_2) = (solve(1), solve(2));
+        answers = part_1.and(part_2).map(|_| ());
+    })?;
+    answers
+}
+
+/// Applies a few random edits to `input`, like inserting, removing, duplicating or swapping characters,
+/// to fuzz the parsers on stable Rust without `cargo fuzz`
+pub fn mutate(rng: &mut Rng, input: &str) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+
+    for _ in 0..rng.random_range(1..=4) {
+        let len = chars.len();
+        let idx = rng.random_range(0..=len);
+        let end = (idx + rng.random_range(1..=8)).min(len);
+
+        match rng.random_range(0..6) {
+            0 => chars.insert(idx, *ALPHABET.choose(rng).unwrap()),
+            1 if idx < len => {
+                chars.drain(idx..end);
+            }
+            2 if idx < len => chars[idx] = *ALPHABET.choose(rng).unwrap(),
+            3 if idx < len => {
+                let copy = chars[idx..end].to_vec();
+                chars.splice(idx..idx, copy);
+            }
+            4 if idx < len => chars.swap(idx, rng.random_range(0..len)),
+            5 => chars.truncate(idx),
+            _ => {}
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::YEARS;
+    use rand::SeedableRng;
+    use std::fs;
+    use std::path::Path;
+
+    /// The samples of a day under `./fuzz/corpus/y<year>_day_XX`, the seed corpus of its fuzz target
+    fn corpus(year: i32, day: u8) -> Vec<String> {
+        let dir = Path::new("./fuzz/corpus").join(format!("y{year}_day_{day:0>2}"));
+        fs::read_dir(&dir)
+            .unwrap_or_else(|e| panic!("no corpus at {}, {e}", dir.display()))
+            .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn corpus_parses() {
+        for &(year, days) in YEARS {
+            for &(day, _) in days {
+                let corpus = corpus(year, day);
+
+                assert!(!corpus.is_empty(), "{year} day {day} has an empty corpus");
+                for input in corpus {
+                    assert_eq!(Ok(()), parse(year, day, input.as_bytes()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solutions_never_panic_on_mutated_corpus() {
+        let mut rng = Rng::seed_from_u64(2025);
+
+        for &(year, days) in YEARS {
+            for &(day, _) in days {
+                for input in corpus(year, day) {
+                    for _ in 0..500 {
+                        let mutated = mutate(&mut rng, &input);
+                        let _ = solve(year, day, mutated.as_bytes());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_ignores_invalid_utf8() {
+        assert_eq!(Ok(()), parse(2025, 1, &[b'L', 0xff, b'1']));
+        assert!(parse(2025, 1, b"X1").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use rand::SeedableRng;
+use rand::rngs::Xoshiro256PlusPlus;
+
+pub mod y2025;
+
+/// Source of randomness for the generators, a fixed algorithm (unlike `StdRng`)
+/// so the same seed keeps producing the same input across `rand` versions.
+pub type Rng = Xoshiro256PlusPlus;
+
+/// Generates a random, yet valid, puzzle input of the requested size.
+/// What the size counts (lines, grid side, devices, ...) differs per day, see each generator.
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// Registry of the input generators of a year, ordered by day number.
+pub type Generators = &'static [(u8, Generator)];
+
+/// Registry of the input generators of every year, ordered by year.
+pub static YEARS: &[(i32, Generators)] = &[(2025, y2025::GENERATORS)];
+
+pub fn generator(year: i32, day: u8) -> Option<Generator> {
+    YEARS
+        .iter()
+        .find(|&&(number, _)| number == year)?
+        .1
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, generator)| generator)
+}
+
+/// Generates an input of `size` for the given day, the same `seed` always giving the same input
+pub fn generate(year: i32, day: u8, size: usize, seed: u64) -> Option<String> {
+    let generator = generator(year, day)?;
+    Some(generator(&mut Rng::seed_from_u64(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::solutions;
+
+    #[test]
+    fn generators_are_ordered_and_registered() {
+        assert!(YEARS.windows(2).all(|years| years[0].0 < years[1].0));
+        for &(year, generators) in YEARS {
+            assert!(generators.windows(2).all(|days| days[0].0 < days[1].0));
+            assert!(
+                generators
+                    .iter()
+                    .all(|&(day, _)| solutions::day(year, day).is_some())
+            );
+        }
+        assert!(generator(2025, 26).is_none());
+    }
+
+    #[test]
+    fn generated_inputs_are_valid() {
+        for &(year, generators) in YEARS {
+            for &(day, _) in generators {
+                let solution = solutions::day(year, day).unwrap();
+
+                for (size, seed) in [(1, 0), (2, 1), (25, 42)] {
+                    let input = generate(year, day, size, seed).unwrap();
+
+                    for answer in [solution.part_1(&input), solution.part_2(&input)] {
+                        assert!(answer.is_ok(), "{year} day {day} size {size}: {answer:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn generated_day_11_paths_pass_through_fft_and_dac() {
+        let solution = solutions::day(2025, 11).unwrap();
+
+        for (size, seed) in [(1, 0), (25, 1), (1_000, 2), (10_000, 3)] {
+            let input = generate(2025, 11, size, seed).unwrap();
+
+            assert_ne!(
+                Ok(Answer::Unsigned(0)),
+                solution.part_2(&input),
+                "size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_reproducible() {
+        for &(year, generators) in YEARS {
+            for &(day, _) in generators {
+                assert_eq!(generate(year, day, 10, 7), generate(year, day, 10, 7));
+                assert_ne!(generate(year, day, 10, 7), generate(year, day, 10, 8));
+            }
+        }
+    }
+}
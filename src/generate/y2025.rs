@@ -0,0 +1,331 @@
+use crate::generate::{Generators, Rng};
+use itertools::Itertools;
+use rand::RngExt;
+use rand::seq::{IndexedRandom, SliceRandom, index};
+use rustc_hash::FxHashSet;
+use std::ops::Range;
+
+/// Registry of the input generators of 2025, ordered by day number.
+pub static GENERATORS: Generators = &[
+    (1, day_01),
+    (2, day_02),
+    (3, day_03),
+    (4, day_04),
+    (5, day_05),
+    (6, day_06),
+    (7, day_07),
+    (8, day_08),
+    (9, day_09),
+    (10, day_10),
+    (11, day_11),
+    (12, day_12),
+];
+
+/// `size` rotations of the dial, like `L68`
+fn day_01(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let direction = if rng.random_bool(0.5) { 'L' } else { 'R' };
+        format!("{direction}{}", rng.random_range(1..1_000))
+    }))
+}
+
+/// `size` product ID ranges of up to 100.000 IDs each, like `11-22`
+fn day_02(rng: &mut Rng, size: usize) -> String {
+    let id_ranges = (0..size)
+        .map(|_| {
+            let digits = rng.random_range(1..=10);
+            let start = rng.random_range(10u64.pow(digits - 1)..10u64.pow(digits));
+            let end = start + rng.random_range(0..100_000);
+            format!("{start}-{end}")
+        })
+        .join(",");
+
+    id_ranges + "\n"
+}
+
+/// `size` banks of 100 batteries each
+fn day_03(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| (0..100).map(|_| rng.random_range(1..=9)).join("")))
+}
+
+/// Square grid of `size` by `size`, with about 60% paper rolls
+fn day_04(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        (0..size)
+            .map(|_| if rng.random_bool(0.6) { '@' } else { '.' })
+            .collect::<String>()
+    }))
+}
+
+/// `size` (overlapping) fresh ranges, followed by `size` ingredient IDs
+fn day_05(rng: &mut Rng, size: usize) -> String {
+    let fresh_ranges = lines((0..size).map(|_| {
+        let start = rng.random_range(1..1_000_000_000_000_000u64);
+        let end = start + rng.random_range(0..10_000_000_000_000);
+        format!("{start}-{end}")
+    }));
+    let ingredients = lines((0..size).map(|_| rng.random_range(1..1_000_000_000_000_000u64)));
+
+    format!("{fresh_ranges}\n{ingredients}")
+}
+
+/// `size` problems of 4 numbers each, aligned either left or right within their column
+fn day_06(rng: &mut Rng, size: usize) -> String {
+    let mut rows = vec![String::new(); 5];
+
+    for problem in 0..size {
+        // Ordered by length, so the digits read top to bottom never have a gap in between
+        let mut numbers = (0..4)
+            .map(|_| rng.random_range(1..1_000).to_string())
+            .sorted_by_key(String::len)
+            .collect_vec();
+        if rng.random_bool(0.5) {
+            numbers.reverse();
+        }
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let operation = if rng.random_bool(0.5) { "+" } else { "*" };
+        let left_aligned = rng.random_bool(0.5);
+
+        let separator = if problem > 0 { " " } else { "" };
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            match left_aligned {
+                true => row.push_str(&format!("{separator}{number:<width$}")),
+                false => row.push_str(&format!("{separator}{number:>width$}")),
+            }
+        }
+        rows[4].push_str(&format!("{separator}{operation:<width$}"));
+    }
+
+    lines(rows)
+}
+
+/// Manifold with `size` rows of splitters, spread in a pyramid below the entry like the real input
+fn day_07(rng: &mut Rng, size: usize) -> String {
+    let width = 2 * size + 3;
+    let entry = size + 1;
+    let mut rows = vec![];
+
+    let mut row = vec!['.'; width];
+    row[entry] = 'S';
+    rows.push(row.iter().collect::<String>());
+
+    for level in 0..size {
+        // Splitters are never adjacent, each only reachable by beams coming from above
+        let mut row = vec!['.'; width];
+        for x in (entry - level..=entry + level).step_by(2) {
+            if rng.random_bool(0.7) {
+                row[x] = '^';
+            }
+        }
+        rows.push(".".repeat(width));
+        rows.push(row.iter().collect());
+    }
+    rows.push(".".repeat(width));
+
+    lines(rows)
+}
+
+/// `size` junction boxes (at least 2) at distinct positions,
+/// with coordinates small enough for the product of two to fit the answer
+fn day_08(rng: &mut Rng, size: usize) -> String {
+    let mut seen = FxHashSet::default();
+    let mut junctions = vec![];
+
+    while junctions.len() < size.max(2) {
+        let junction = (
+            rng.random_range(0..=u16::MAX),
+            rng.random_range(0..=u16::MAX),
+            rng.random_range(0..=u16::MAX),
+        );
+        if seen.insert(junction) {
+            junctions.push(junction);
+        }
+    }
+
+    lines(junctions.iter().map(|(x, y, z)| format!("{x},{y},{z}")))
+}
+
+/// Loop of about `size` red tiles (a multiple of 4), forming a simple rectilinear polygon.
+/// Its top edge zigzags above the middle and its bottom edge below it, so the two never cross.
+fn day_09(rng: &mut Rng, size: usize) -> String {
+    let strips = (size / 4).max(1);
+    let extent = 100_000.max(4 * (strips + 1));
+    let middle = extent as u64 / 2;
+
+    let xs = index::sample(rng, extent, strips + 1)
+        .into_iter()
+        .map(|x| x as u64)
+        .sorted()
+        .collect_vec();
+    let mut heights = |range: Range<u64>| {
+        let mut heights: Vec<u64> = vec![];
+        while heights.len() < strips {
+            let height = rng.random_range(range.clone());
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        heights
+    };
+    let tops = heights(middle + 1..2 * middle);
+    let bottoms = heights(0..middle);
+
+    let mut red_tiles = vec![(xs[0], tops[0])];
+    for strip in 1..=strips {
+        red_tiles.push((xs[strip], tops[strip - 1]));
+        if strip < strips {
+            red_tiles.push((xs[strip], tops[strip]));
+        }
+    }
+    red_tiles.push((xs[strips], bottoms[strips - 1]));
+    for strip in (0..strips).rev() {
+        red_tiles.push((xs[strip], bottoms[strip]));
+        if strip > 0 {
+            red_tiles.push((xs[strip], bottoms[strip - 1]));
+        }
+    }
+
+    lines(red_tiles.iter().map(|(x, y)| format!("{x},{y}")))
+}
+
+/// `size` machines of 4 to 10 lights, whose lights and joltages are reachable by pressing their buttons
+fn day_10(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let nr_lights = rng.random_range(4..=10);
+        let nr_buttons = rng.random_range(nr_lights - 2..=nr_lights + 3);
+        let buttons = (0..nr_buttons)
+            .map(|_| {
+                let amount = rng.random_range(1..=nr_lights);
+                index::sample(rng, nr_lights, amount)
+                    .into_iter()
+                    .sorted()
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        // Derive the lights and joltages from random button presses, so both can be reached
+        let mut lights = vec![false; nr_lights];
+        let mut joltages = vec![0; nr_lights];
+        for button in &buttons {
+            let presses = rng.random_range(0..=20);
+            for &light in button {
+                lights[light] ^= presses % 2 == 1;
+                joltages[light] += presses;
+            }
+        }
+
+        let lights = lights
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect::<String>();
+        let buttons = buttons
+            .iter()
+            .map(|button| format!("({})", button.iter().join(",")))
+            .join(" ");
+        format!("[{lights}] {buttons} {{{}}}", joltages.iter().join(","))
+    }))
+}
+
+/// Acyclic wiring of `size` devices (at least 5) besides `out`, including `svr`, `you`, `fft` and `dac`.
+/// Every device leads to `out`, through the next of `fft` and `dac` still ahead of it,
+/// with further outputs only added while the number of paths fits comfortably in the answer.
+fn day_11(rng: &mut Rng, size: usize) -> String {
+    const MAX_PATHS: u64 = 1 << 50;
+    const RESERVED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+    let size = size.max(5);
+    let mut middle = ["fft", "dac"];
+    middle.shuffle(rng);
+
+    let mut names = (0..)
+        .map(|mut idx: usize| {
+            let mut name = vec![];
+            while name.len() < 3 || idx > 0 {
+                name.push((b'a' + (idx % 26) as u8) as char);
+                idx /= 26;
+            }
+            name.iter().rev().collect::<String>()
+        })
+        .filter(|name| !RESERVED.contains(&name.as_str()))
+        .take(size - 4)
+        .collect_vec();
+    names.insert(0, "svr".to_string());
+    names.insert(size / 10 + 1, "you".to_string());
+    names.insert(size / 3, middle[0].to_string());
+    names.insert(2 * size / 3, middle[1].to_string());
+    names.push("out".to_string());
+    let landmarks = ["fft", "dac", "out"]
+        .map(|landmark| names.iter().position(|name| name == landmark).unwrap());
+
+    // Devices only output to the next few ones, walked backwards to count the paths to `out`.
+    // The first output never skips the next landmark, so every device leads to it, the others may.
+    let mut paths = vec![0; size + 1];
+    paths[size] = 1;
+    let mut devices = vec![];
+    for device in (0..size).rev() {
+        let landmark = landmarks
+            .into_iter()
+            .filter(|&landmark| landmark > device)
+            .min()
+            .unwrap();
+        let next = (device + 1..=(device + 20).min(size)).collect_vec();
+        let nr_outputs = rng.random_range(1..=4).min(next.len());
+
+        let first = rng.random_range(device + 1..=(device + 20).min(landmark));
+        paths[device] = paths[first];
+        let mut outputs = vec![&names[first]];
+        for &output in next.sample(rng, nr_outputs) {
+            if output != first && paths[device] + paths[output] <= MAX_PATHS {
+                paths[device] += paths[output];
+                outputs.push(&names[output]);
+            }
+        }
+        devices.push(format!("{}: {}", names[device], outputs.iter().join(" ")));
+    }
+    devices.shuffle(rng);
+
+    lines(devices)
+}
+
+/// 6 shapes of 5 to 7 cells, followed by `size` regions of 3 to 5 cells either way, holding 1 to 3 presents.
+/// Unlike the real input many presents only fit by interlocking, if at all, which takes backtracking to find out,
+/// so the regions are kept small enough to backtrack over exhaustively.
+fn day_12(rng: &mut Rng, size: usize) -> String {
+    let shapes = (0..6)
+        .map(|idx| {
+            let nr_cells = rng.random_range(5..=7);
+            let cells = index::sample(rng, 9, nr_cells).into_vec();
+            let rows = (0..3)
+                .map(|row| {
+                    (0..3)
+                        .map(|col| match cells.contains(&(row * 3 + col)) {
+                            true => '#',
+                            false => '.',
+                        })
+                        .collect::<String>()
+                })
+                .join("\n");
+            format!("{idx}:\n{rows}")
+        })
+        .join("\n\n");
+    let regions = lines((0..size).map(|_| {
+        let width = rng.random_range(3..=5);
+        let height = rng.random_range(3..=5);
+
+        let mut counts = [0; 6];
+        for _ in 0..rng.random_range(1..=3) {
+            counts[rng.random_range(0..6)] += 1;
+        }
+        format!("{width}x{height}: {}", counts.iter().join(" "))
+    }));
+
+    format!("{shapes}\n\n{regions}")
+}
+
+/// Joins `lines` into an input, each ending with a newline like the real ones
+fn lines<T: ToString>(lines: impl IntoIterator<Item = T>) -> String {
+    lines
+        .into_iter()
+        .map(|line| line.to_string() + "\n")
+        .collect()
+}
@@ -0,0 +1,147 @@
+use crate::answer::Answer;
+use crate::client::Verdict;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+pub const GUESSES_PATH: &str = "./guesses.toml";
+
+/// Log of all answers submitted per year, day and part, stored in `guesses.toml` as
+/// ```toml
+/// [[2025.day_01.part_1]]
+/// answer = "1200"
+/// verdict = "too_high"
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guesses {
+    years: BTreeMap<String, BTreeMap<String, DayGuesses>>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct DayGuesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part_1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part_2: Vec<Guess>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Guesses {
+    /// Loads the log at `path`, a missing file simply has no guesses logged yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(guesses) => Ok(toml::from_str(&guesses)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: i32, day: u8, part: u8) -> &[Guess] {
+        let days = self.years.get(&year.to_string());
+        let Some(guesses) = days.and_then(|days| days.get(&Self::key(day))) else {
+            return &[];
+        };
+        match part {
+            1 => &guesses.part_1,
+            2 => &guesses.part_2,
+            _ => &[],
+        }
+    }
+
+    pub fn record(&mut self, year: i32, day: u8, part: u8, answer: &Answer, verdict: Verdict) {
+        let days = self.years.entry(year.to_string()).or_default();
+        let guesses = days.entry(Self::key(day)).or_default();
+        let guess = Guess {
+            answer: answer.to_string(),
+            verdict,
+        };
+        match part {
+            1 => guesses.part_1.push(guess),
+            2 => guesses.part_2.push(guess),
+            _ => panic!("Invalid part {part}, expected 1 or 2"),
+        }
+    }
+
+    /// The earlier guess that shows `answer` is wrong without submitting it:
+    /// either the same answer judged wrong, or a numeric answer beyond one judged too high or too low
+    pub fn ruled_out_by(&self, year: i32, day: u8, part: u8, answer: &Answer) -> Option<&Guess> {
+        let answer = answer.to_string();
+        let number = answer.parse::<i128>().ok();
+
+        self.get(year, day, part).iter().find(|guess| {
+            let guess_number = guess.answer.parse::<i128>().ok();
+            match (guess.verdict, number, guess_number) {
+                (Verdict::Correct, _, _) => false,
+                (_, _, _) if guess.answer == answer => true,
+                (Verdict::TooHigh, Some(number), Some(guess)) => number >= guess,
+                (Verdict::TooLow, Some(number), Some(guess)) => number <= guess,
+                _ => false,
+            }
+        })
+    }
+
+    fn key(day: u8) -> String {
+        format!("day_{day:0>2}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guesses_round_trip() {
+        let mut guesses = Guesses::default();
+        guesses.record(2025, 1, 1, &Answer::Unsigned(1200), Verdict::TooHigh);
+        guesses.record(2025, 1, 1, &Answer::Unsigned(1123), Verdict::Correct);
+
+        let log = toml::to_string(&guesses).unwrap();
+
+        assert_eq!(guesses, toml::from_str(&log).unwrap());
+        assert!(log.starts_with("[[2025.day_01.part_1]]"));
+        assert_eq!(2, guesses.get(2025, 1, 1).len());
+        assert!(guesses.get(2025, 1, 2).is_empty());
+        assert!(guesses.get(2024, 1, 1).is_empty());
+    }
+
+    #[test]
+    fn guesses_rule_out_known_wrong_answers() {
+        let mut guesses = Guesses::default();
+        guesses.record(2025, 1, 1, &Answer::Unsigned(1200), Verdict::TooHigh);
+        guesses.record(2025, 1, 1, &Answer::Unsigned(1000), Verdict::TooLow);
+        guesses.record(2025, 1, 1, &Answer::from("1100"), Verdict::Wrong);
+
+        let ruled_out = |answer: u64| {
+            let guess = guesses.ruled_out_by(2025, 1, 1, &Answer::from(answer));
+            guess.map(|guess| guess.verdict)
+        };
+
+        assert_eq!(Some(Verdict::TooHigh), ruled_out(1300));
+        assert_eq!(Some(Verdict::TooHigh), ruled_out(1200));
+        assert_eq!(Some(Verdict::TooLow), ruled_out(900));
+        assert_eq!(Some(Verdict::Wrong), ruled_out(1100));
+        assert_eq!(None, ruled_out(1123));
+        assert_eq!(
+            None,
+            guesses.ruled_out_by(2025, 1, 2, &Answer::from(1300_u64))
+        );
+        assert_eq!(
+            None,
+            guesses.ruled_out_by(2024, 1, 1, &Answer::from(1300_u64))
+        );
+    }
+}
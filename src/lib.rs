@@ -1,4 +1,15 @@
 extern crate core;
 
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod client;
+pub mod fuzz;
+pub mod generate;
+pub mod guesses;
+pub mod memory;
+pub mod parse;
+pub mod puzzle;
+pub mod reference;
 pub mod solutions;
-mod util;
+pub mod y2025;
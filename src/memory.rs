@@ -0,0 +1,141 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-profile")]
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+#[cfg(feature = "alloc-profile")]
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator keeping track of how much heap memory is in use, and its peak.
+/// With the `alloc-profile` feature it also counts every allocation and the bytes allocated,
+/// at the cost of some extra overhead on each of them.
+///
+/// Only has an effect when installed by a binary:
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+/// ```
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn allocated(size: usize) {
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+
+    #[cfg(feature = "alloc-profile")]
+    {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    }
+}
+
+/// Heap usage of a closure run through [`measure`]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct MemoryUsage {
+    /// Most heap memory (in bytes) in use at once, on top of what was already in use before
+    pub peak: usize,
+    /// Number of allocations, a reallocation counting as one, `None` without the `alloc-profile` feature
+    pub allocations: Option<usize>,
+    /// Total bytes allocated, `None` without the `alloc-profile` feature
+    pub allocated_bytes: Option<usize>,
+}
+
+/// Runs `f`, also returning its heap usage.
+///
+/// All counts are 0 unless the [`TrackingAllocator`] is installed.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryUsage) {
+    let before = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let (allocations_before, bytes_before) = counters();
+
+    let result = f();
+
+    let (allocations_after, bytes_after) = counters();
+    let usage = MemoryUsage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(before),
+        allocations: allocations_after
+            .zip(allocations_before)
+            .map(|(a, b)| a - b),
+        allocated_bytes: bytes_after.zip(bytes_before).map(|(a, b)| a - b),
+    };
+    (result, usage)
+}
+
+/// Allocations and bytes allocated so far, only counted with the `alloc-profile` feature
+#[cfg(feature = "alloc-profile")]
+fn counters() -> (Option<usize>, Option<usize>) {
+    (
+        Some(ALLOCATIONS.load(Ordering::Relaxed)),
+        Some(ALLOCATED_BYTES.load(Ordering::Relaxed)),
+    )
+}
+
+#[cfg(not(feature = "alloc-profile"))]
+fn counters() -> (Option<usize>, Option<usize>) {
+    (None, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_tracks_tracking_allocator() {
+        let small = Layout::from_size_align(1_024, 8).unwrap();
+        let large = Layout::from_size_align(4_096, 8).unwrap();
+        let before = unsafe { TrackingAllocator.alloc(large) };
+
+        let ((), usage) = measure(|| unsafe {
+            let a = TrackingAllocator.alloc(small);
+            let b = TrackingAllocator.alloc_zeroed(small);
+            TrackingAllocator.dealloc(a, small);
+            let b = TrackingAllocator.realloc(b, small, large.size());
+            TrackingAllocator.dealloc(b, large);
+        });
+        unsafe { TrackingAllocator.dealloc(before, large) };
+
+        assert_eq!(4_096, usage.peak);
+        assert_eq!(
+            cfg!(feature = "alloc-profile").then_some(3),
+            usage.allocations
+        );
+        assert_eq!(
+            cfg!(feature = "alloc-profile").then_some(6_144),
+            usage.allocated_bytes
+        );
+        assert_eq!(0, CURRENT.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,193 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error for puzzle input that does not have the expected form, or that parsed but has no answer.
+///
+/// Positions are 1-based, and relative to the full puzzle input once the error bubbles up:
+/// parsers for a single line or block report positions within that line or block,
+/// which callers then shift using [`ParseError::shift_lines`] or [`ParseError::offset_by`].
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: &'static str,
+    pub kind: ErrorKind,
+}
+
+/// Phase in which a [`ParseError`] occurred
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum ErrorKind {
+    /// The input does not have the expected form
+    Parse,
+    /// The input parsed, but solving a part on it failed
+    Solve,
+}
+
+impl ParseError {
+    pub fn at(line: usize, column: usize, text: &str, expected: &'static str) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            expected,
+            kind: ErrorKind::Parse,
+        }
+    }
+
+    /// Error for input that parsed, but on which solving a part failed because of `text` at that position
+    pub fn unsolvable(line: usize, column: usize, text: &str, expected: &'static str) -> Self {
+        Self {
+            kind: ErrorKind::Solve,
+            ..Self::at(line, column, text, expected)
+        }
+    }
+
+    /// Error for input that parsed, but on which solving a part failed because of something missing from it as a whole,
+    /// not positioned at any line
+    pub fn unsolvable_input(expected: &'static str) -> Self {
+        Self::unsolvable(0, 0, "", expected)
+    }
+
+    /// Error for `part`, a sub-slice of `s`, positioned at where `part` starts within `s`
+    pub fn within(s: &str, part: &str, expected: &'static str) -> Self {
+        let (line, column) = position(s, part);
+        Self::at(line, column, part, expected)
+    }
+
+    /// Moves the error down by `lines`, for when the parsed string was a line of a larger input
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves the error to be relative to `outer`, for when the parsed `s` is a sub-slice of it
+    pub fn offset_by(mut self, s: &str, outer: &str) -> Self {
+        let (line, column) = position(outer, s);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.kind, self.line) {
+            (ErrorKind::Parse, _) => write!(
+                f,
+                "line {}, column {}: expected {}, found `{}`",
+                self.line, self.column, self.expected, self.text
+            ),
+            (ErrorKind::Solve, 0) => write!(f, "cannot solve, expected {}", self.expected),
+            (ErrorKind::Solve, _) => write!(
+                f,
+                "line {}, column {}: cannot solve, expected {}, found `{}`",
+                self.line, self.column, self.expected, self.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every line as a `T`, reporting errors at the line they occurred on
+pub fn parse_lines<T: FromStr<Err = ParseError>>(lines: &[&str]) -> Result<Vec<T>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| line.parse().map_err(|e: ParseError| e.shift_lines(idx)))
+        .collect()
+}
+
+/// Parses `part`, a sub-slice of `outer`, reporting errors relative to `outer`
+pub fn parse_within<T: FromStr<Err = ParseError>>(
+    part: &str,
+    outer: &str,
+) -> Result<T, ParseError> {
+    part.parse()
+        .map_err(|e: ParseError| e.offset_by(part, outer))
+}
+
+/// Parses `part`, a sub-slice of `s`, as a number
+pub fn parse_number<T: FromStr>(
+    s: &str,
+    part: &str,
+    expected: &'static str,
+) -> Result<T, ParseError> {
+    part.parse()
+        .map_err(|_| ParseError::within(s, part, expected))
+}
+
+/// 1-based line and column at which `part` starts within `s`,
+/// falling back to the very start of `s` when `part` isn't a sub-slice of it
+fn position(s: &str, part: &str) -> (usize, usize) {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(s.as_ptr() as usize)
+        .filter(|&offset| offset + part.len() <= s.len() && s.is_char_boundary(offset))
+        .unwrap_or(0);
+    let before = &s[..offset];
+
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit_once('\n')
+        .map_or(before, |(_, last_line)| last_line)
+        .chars()
+        .count()
+        + 1;
+
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_reports_position_of_part() {
+        let s = "3-5\n10-1x\n";
+        let error = ParseError::within(s, &s[7..9], "a number");
+
+        assert_eq!(ParseError::at(2, 4, "1x", "a number"), error);
+        assert_eq!(
+            "line 2, column 4: expected a number, found `1x`",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn unsolvable_is_not_a_parse_failure() {
+        assert_eq!(
+            "line 3, column 1: cannot solve, expected a bank of 12 batteries, found `123`",
+            ParseError::unsolvable(3, 1, "123", "a bank of 12 batteries").to_string()
+        );
+        assert_eq!(
+            "cannot solve, expected a device `you`",
+            ParseError::unsolvable_input("a device `you`").to_string()
+        );
+        assert_ne!(
+            ParseError::at(1, 1, "", "a device `you`"),
+            ParseError::unsolvable(1, 1, "", "a device `you`")
+        );
+    }
+
+    #[test]
+    fn within_falls_back_to_start_for_unrelated_part() {
+        let error = ParseError::within("abc", "xyz", "a letter");
+
+        assert_eq!(ParseError::at(1, 1, "xyz", "a letter"), error);
+    }
+
+    #[test]
+    fn offset_by_shifts_into_outer() {
+        let outer = "a,b\nccc,d1";
+        let part = &outer[8..];
+        let error = parse_number::<u32>(part, part, "a number")
+            .unwrap_err()
+            .offset_by(part, outer);
+
+        assert_eq!(ParseError::at(2, 5, "d1", "a number"), error);
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The first year Advent of Code took place
+pub const FIRST_YEAR: i32 = 2015;
+
+static ARTICLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static SAMPLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap());
+static TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+static BLANK_LINES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\n{3,}").unwrap());
+
+/// A puzzle description, as published on the day's page of the Advent of Code website
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Puzzle {
+    /// The description of all unlocked parts, as plain text
+    pub text: String,
+    /// The first example in the description, usually the sample input
+    pub sample: Option<String>,
+}
+
+impl Puzzle {
+    pub fn from_html(html: &str) -> Result<Self, &'static str> {
+        let articles = ARTICLE
+            .captures_iter(html)
+            .map(|article| to_text(&article[1]))
+            .collect::<Vec<_>>();
+        if articles.is_empty() {
+            return Err("no puzzle description found in the page");
+        }
+
+        let sample = SAMPLE
+            .captures(html)
+            .map(|sample| decode_entities(&TAG.replace_all(&sample[1], "")));
+
+        Ok(Self {
+            text: articles.join("\n\n") + "\n",
+            sample,
+        })
+    }
+}
+
+/// Number of days in `year`'s calendar, 25 until 2025 shortened it to 12
+pub fn last_day(year: i32) -> u8 {
+    if year < 2025 { 25 } else { 12 }
+}
+
+/// When `day`'s puzzle of `year` unlocks: at midnight in the puzzle's timezone, US Eastern Standard Time
+pub fn unlock_time(year: i32, day: u8) -> Result<DateTime<FixedOffset>, String> {
+    if year < FIRST_YEAR {
+        return Err(format!(
+            "invalid year {year}, expected {FIRST_YEAR} or later"
+        ));
+    }
+    let last_day = last_day(year);
+    if !(1..=last_day).contains(&day) {
+        return Err(format!("invalid day {day}, expected 1 to {last_day}"));
+    }
+
+    let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+    Ok(eastern
+        .with_ymd_and_hms(year, 12, day as u32, 0, 0, 0)
+        .unwrap())
+}
+
+fn to_text(article: &str) -> String {
+    let text = article
+        .replace("</h2>", "\n\n")
+        .replace("</p>", "\n\n")
+        .replace("<li>", "- ")
+        .replace("</li>", "\n")
+        .replace("</ul>", "\n")
+        .replace("</pre>", "\n");
+    let text = decode_entities(&TAG.replace_all(&text, ""));
+
+    BLANK_LINES.replace_all(text.trim(), "\n\n").to_string()
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::SecondsFormat;
+
+    const HTML: &str = "\
+<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>The <em>dial</em> starts at <code>50</code>.</p>
+<p>For example:</p>
+<pre><code>L68
+L30 &amp; <em>R48</em>
+</code></pre>
+<ul>
+<li>Rotate &lt;left&gt;.</li>
+</ul>
+</article>
+</main></body></html>";
+
+    #[test]
+    fn puzzle_from_html() {
+        let puzzle = Puzzle::from_html(HTML).unwrap();
+
+        assert_eq!(
+            "--- Day 1: Secret Entrance ---\n\n\
+            The dial starts at 50.\n\n\
+            For example:\n\n\
+            L68\nL30 & R48\n\n\
+            - Rotate <left>.\n",
+            puzzle.text
+        );
+        assert_eq!(Some("L68\nL30 & R48\n".to_string()), puzzle.sample);
+        assert!(Puzzle::from_html("<html></html>").is_err());
+    }
+
+    #[test]
+    fn puzzle_unlock_time() {
+        assert_eq!(
+            "2025-12-01T05:00:00Z",
+            unlock_time(2025, 1)
+                .unwrap()
+                .to_utc()
+                .to_rfc3339_opts(SecondsFormat::Secs, true)
+        );
+        assert!(unlock_time(2025, 0).is_err());
+        assert!(unlock_time(2025, 13).is_err());
+        assert!(unlock_time(2024, 25).is_ok());
+        assert!(unlock_time(2014, 1).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::answer::Answer;
+use crate::generate;
+use crate::parse::ParseError;
+use crate::solutions::Reference;
+use itertools::Itertools;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic;
+use std::panic::AssertUnwindSafe;
+
+/// Input on which the optimized part of a day disagrees with its reference (or either panics),
+/// shrunk down to as few of the day's units, see [`Reference::SEPARATOR`], as still disagree
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub part: u8,
+    pub input: String,
+    pub optimized: Outcome,
+    pub reference: Outcome,
+}
+
+/// What running a part on an input came down to
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Answer(Answer),
+    Error(ParseError),
+    /// The part panicked, with the panic's message
+    Panic(String),
+}
+
+/// Runs both the optimized parts of `S` and their reference on `cases` generated inputs,
+/// the n-th one generated with seed n and a size slowly growing from 1, so the first mismatch found is a small one.
+/// That mismatch is then shrunk further, removing parts of the input for as long as the mismatch remains.
+///
+/// # Panics
+///
+/// If no generator is registered for the day.
+pub fn differential<S: Reference>(year: i32, day: u8, cases: u64) -> Result<(), Box<Mismatch>> {
+    for seed in 0..cases {
+        let size = 1 + seed as usize / 5;
+        let input = generate::generate(year, day, size, seed)
+            .unwrap_or_else(|| panic!("day {day} of {year} has no input generator"));
+
+        for part in [1, 2] {
+            if disagrees::<S>(&input, part) {
+                let input = shrink(&input, S::SEPARATOR, |input| disagrees::<S>(input, part));
+                let (optimized, reference) = answers::<S>(&input, part);
+
+                return Err(Box::new(Mismatch {
+                    seed,
+                    size,
+                    part,
+                    input,
+                    optimized,
+                    reference,
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Outcomes of the optimized part and its reference, both the parse error if the input does not parse
+fn answers<S: Reference>(input: &str, part: u8) -> (Outcome, Outcome) {
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| S::parse(input))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => return (Outcome::Error(e.clone()), Outcome::Error(e)),
+        Err(payload) => {
+            let message = panic_message(payload);
+            return (Outcome::Panic(message.clone()), Outcome::Panic(message));
+        }
+    };
+
+    match part {
+        1 => (
+            outcome(|| S::part_1(&parsed).map(Into::into)),
+            outcome(|| S::reference_1(&parsed).map(Into::into)),
+        ),
+        _ => (
+            outcome(|| S::part_2(&parsed).map(Into::into)),
+            outcome(|| S::reference_2(&parsed).map(Into::into)),
+        ),
+    }
+}
+
+/// Runs `part`, catching a panic so it is reported rather than aborting the whole differential check
+fn outcome(part: impl FnOnce() -> Result<Answer, ParseError>) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(part)) {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(e)) => Outcome::Error(e),
+        Err(payload) => Outcome::Panic(panic_message(payload)),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| "no message".to_string(), |message| message.to_string()),
+    }
+}
+
+/// Whether the input makes the optimized part or its reference panic,
+/// or parses and the optimized part and its reference disagree on it
+fn disagrees<S: Reference>(input: &str, part: u8) -> bool {
+    match answers::<S>(input, part) {
+        (Outcome::Panic(_), _) | (_, Outcome::Panic(_)) => true,
+        (optimized, reference) => optimized != reference,
+    }
+}
+
+/// Removes ever smaller chunks of units, split on `separator`,
+/// from `input` for as long as it keeps `failing`, until no single one can be removed anymore
+fn shrink(input: &str, separator: &str, failing: impl Fn(&str) -> bool) -> String {
+    let trimmed = input.trim_end();
+    let join = |units: &[&str]| units.join(separator) + "\n";
+
+    let mut units = trimmed.split(separator).collect_vec();
+    let mut chunk = units.len() / 2;
+
+    while chunk > 0 {
+        let mut start = 0;
+        let mut shrunk = false;
+
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+
+            if !candidate.is_empty() && failing(&join(&candidate)) {
+                units = candidate;
+                shrunk = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+
+    join(&units)
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let answer = |outcome: &Outcome| match outcome {
+            Outcome::Answer(answer) => answer.to_string(),
+            Outcome::Error(e) => format!("error, {e}"),
+            Outcome::Panic(message) => format!("panicked, {message}"),
+        };
+
+        writeln!(
+            f,
+            "part {} disagrees with its reference on the input generated with seed {} and size {}, shrunk to",
+            self.part, self.seed, self.size
+        )?;
+        writeln!(f, "{}", self.input)?;
+        writeln!(f, "optimized: {}", answer(&self.optimized))?;
+        write!(f, "reference: {}", answer(&self.reference))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solutions::Solution;
+
+    /// Sums the rotation distances of day 01, but the "optimized" part 1 forgets about any rotation of 13
+    struct Broken;
+
+    impl Solution for Broken {
+        type Input<'a> = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line[1..]
+                        .parse()
+                        .map_err(|_| ParseError::at(1, 1, line, "a rotation"))
+                })
+                .collect()
+        }
+
+        fn part_1(distances: &Self::Input<'_>) -> Result<u32, ParseError> {
+            Ok(distances.iter().filter(|&&distance| distance != 13).sum())
+        }
+
+        fn part_2(distances: &Self::Input<'_>) -> Result<u32, ParseError> {
+            Ok(distances.iter().sum())
+        }
+    }
+
+    impl Reference for Broken {
+        fn reference_1(distances: &Self::Input<'_>) -> Result<u32, ParseError> {
+            Ok(distances.iter().sum())
+        }
+
+        fn reference_2(distances: &Self::Input<'_>) -> Result<u32, ParseError> {
+            Ok(distances.iter().sum())
+        }
+    }
+
+    #[test]
+    fn differential_shrinks_mismatch() {
+        let mismatch = differential::<Broken>(2025, 1, 1_000).unwrap_err();
+
+        assert_eq!(1, mismatch.part);
+        assert!(mismatch.input == "L13\n" || mismatch.input == "R13\n");
+        assert_eq!(Outcome::Answer(Answer::Unsigned(0)), mismatch.optimized);
+        assert_eq!(Outcome::Answer(Answer::Unsigned(13)), mismatch.reference);
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let overflow = |distance: u8| -> Result<Answer, ParseError> {
+            Ok(Answer::Unsigned(
+                distance.checked_add(u8::MAX).expect("overflow").into(),
+            ))
+        };
+
+        assert_eq!(
+            Outcome::Answer(Answer::Unsigned(255)),
+            outcome(|| overflow(0))
+        );
+        assert_eq!(
+            Outcome::Panic("overflow".to_string()),
+            outcome(|| overflow(1))
+        );
+        assert_eq!(Outcome::Panic("5".to_string()), outcome(|| panic!("{}", 5)));
+    }
+
+    #[test]
+    fn shrink_keeps_failing_units() {
+        let items = |input: &str| input.contains("3-5") && input.contains("9-9");
+        let lines = |input: &str| input.contains("b\n") && input.contains("d\n");
+
+        assert_eq!("3-5,9-9\n", shrink("1-2,3-5,6-8,9-9,10-12\n", ",", items));
+        assert_eq!("b\nd\n", shrink("a\nb\nc\nd\ne\n", "\n", lines));
+    }
+}
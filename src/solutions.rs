@@ -1,12 +1,179 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
+use crate::answer::Answer;
+use crate::memory;
+use crate::memory::MemoryUsage;
+use crate::parse::ParseError;
+use std::time::{Duration, Instant};
+
+/// Common shape of every day's solution, so days can be called generically.
+///
+/// `parse` turns the raw puzzle input into the day's parsed input type, doing all the setup shared by both parts once,
+/// the parts then compute their answer from that parsed input, of any type convertible into an [`Answer`].
+/// Malformed input is reported as a [`ParseError`] rather than a panic,
+/// as is input that parses but has no answer, [`unsolvable`](ParseError::unsolvable) rather than at a made up position.
+pub trait Solution {
+    type Input<'a>;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError>;
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError>;
+}
+
+/// Straightforward (and usually slow) implementation of both parts of a day, kept alongside the optimized [`Solution`]
+/// to check it against, see [`reference::differential`](crate::reference::differential).
+pub trait Reference: Solution {
+    /// Separates the units of the day's input a mismatch is shrunk by, lines unless the input is a single list
+    const SEPARATOR: &'static str = "\n";
+
+    fn reference_1(input: &Self::Input<'_>) -> Result<Self::Answer1, ParseError>;
+
+    fn reference_2(input: &Self::Input<'_>) -> Result<Self::Answer2, ParseError>;
+}
+
+/// Object-safe counterpart of [`Solution`], used to store days of different types in [`DAYS`].
+pub trait AnySolution: Sync {
+    fn part_1(&self, input: &str) -> Result<Answer, ParseError>;
+
+    fn part_2(&self, input: &str) -> Result<Answer, ParseError>;
+
+    /// Only parses `input`, discarding the result
+    fn parse(&self, input: &str) -> Result<(), ParseError>;
+
+    /// Parses `input` once, then hands `f` a function solving either part on that parsed input
+    fn with_parsed(&self, input: &str, f: &mut dyn FnMut(&SolvePart)) -> Result<(), ParseError>;
+
+    /// Runs `part` on `input`, measuring the time spent parsing and solving separately
+    fn run(&self, part: u8, input: &str) -> PartRun;
+}
+
+/// Solves the given part on input parsed beforehand, see [`AnySolution::with_parsed`]
+pub type SolvePart<'a> = dyn Fn(u8) -> Result<Answer, ParseError> + 'a;
+
+/// Answer and measurements of running a single part
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub answer: Result<Answer, ParseError>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// Heap usage during the run, see [`memory::measure`]
+    pub memory: MemoryUsage,
+}
+
+impl<S: Solution + Sync> AnySolution for S {
+    fn part_1(&self, input: &str) -> Result<Answer, ParseError> {
+        S::part_1(&S::parse(input)?).map(Into::into)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, ParseError> {
+        S::part_2(&S::parse(input)?).map(Into::into)
+    }
+
+    fn parse(&self, input: &str) -> Result<(), ParseError> {
+        S::parse(input).map(|_| ())
+    }
+
+    fn with_parsed(&self, input: &str, f: &mut dyn FnMut(&SolvePart)) -> Result<(), ParseError> {
+        let parsed = S::parse(input)?;
+        f(&|part| solve::<S>(&parsed, part));
+        Ok(())
+    }
+
+    fn run(&self, part: u8, input: &str) -> PartRun {
+        let ((answer, parse_time, solve_time), memory) = memory::measure(|| {
+            let start = Instant::now();
+            let parsed = S::parse(input);
+            let parse_time = start.elapsed();
+
+            let start = Instant::now();
+            let answer = parsed.and_then(|parsed| solve::<S>(&parsed, part));
+
+            (answer, parse_time, start.elapsed())
+        });
+
+        PartRun {
+            answer,
+            parse_time,
+            solve_time,
+            memory,
+        }
+    }
+}
+
+fn solve<S: Solution>(parsed: &S::Input<'_>, part: u8) -> Result<Answer, ParseError> {
+    match part {
+        1 => S::part_1(parsed).map(Into::into),
+        _ => S::part_2(parsed).map(Into::into),
+    }
+}
+
+/// Registry of all solved days, ordered by day number.
+pub type Days = &'static [(u8, &'static dyn AnySolution)];
+
+/// Registry of the solved days of every year, ordered by year.
+pub static YEARS: &[(i32, Days)] = &[(2025, crate::y2025::DAYS)];
+
+/// The most recent year with solved days, used whenever no year is given.
+pub fn latest_year() -> i32 {
+    YEARS[YEARS.len() - 1].0
+}
+
+pub fn days(year: i32) -> Option<Days> {
+    YEARS
+        .iter()
+        .find(|&&(number, _)| number == year)
+        .map(|&(_, days)| days)
+}
+
+pub fn day(year: i32, day: u8) -> Option<&'static dyn AnySolution> {
+    days(year)?
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, solution)| solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_is_ordered_by_year_and_day() {
+        assert!(YEARS.windows(2).all(|years| years[0].0 < years[1].0));
+        assert!(
+            YEARS
+                .iter()
+                .all(|(_, days)| days.windows(2).all(|days| days[0].0 < days[1].0))
+        );
+    }
+
+    #[test]
+    fn registry_runs_day_sample() {
+        let sample = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let solution = day(2025, 1).unwrap();
+
+        assert_eq!(Ok(Answer::Unsigned(3)), solution.part_1(sample));
+        assert_eq!(Ok(Answer::Unsigned(6)), solution.part_2(sample));
+        assert!(day(2025, 26).is_none());
+        assert!(day(2014, 1).is_none());
+        assert_eq!(2025, latest_year());
+    }
+
+    #[test]
+    fn registry_reuses_parsed_input() {
+        let sample = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let solution = day(2025, 1).unwrap();
+        let mut answers = vec![];
+
+        solution
+            .with_parsed(sample, &mut |solve| answers.extend([solve(1), solve(2)]))
+            .unwrap();
+
+        assert_eq!(
+            vec![Ok(Answer::Unsigned(3)), Ok(Answer::Unsigned(6))],
+            answers
+        );
+        assert!(solution.parse("X1").is_err());
+    }
+}
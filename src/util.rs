@@ -1,21 +0,0 @@
-#![allow(dead_code)]
-
-pub mod a_star;
-pub mod circular_list;
-pub mod union_find;
-
-pub const BASE_10: u32 = 10;
-
-pub fn lcm(a: u64, b: u64) -> u64 {
-    a / gcd(a, b) * b
-}
-
-pub fn gcd(a: u64, b: u64) -> u64 {
-    let mut pair = (a, b);
-
-    while pair.1 > 0 {
-        pair = (pair.1, pair.0 % pair.1)
-    }
-
-    pair.0
-}
@@ -0,0 +1,30 @@
+use crate::solutions::Days;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+
+/// Registry of all solved days of 2025, ordered by day number.
+pub static DAYS: Days = &[
+    (1, &day_01::Day01),
+    (2, &day_02::Day02),
+    (3, &day_03::Day03),
+    (4, &day_04::Day04),
+    (5, &day_05::Day05),
+    (6, &day_06::Day06),
+    (7, &day_07::Day07),
+    (8, &day_08::Day08),
+    (9, &day_09::Day09),
+    (10, &day_10::Day10),
+    (11, &day_11::Day11),
+    (12, &day_12::Day12),
+];
@@ -0,0 +1,248 @@
+use crate::parse::{ParseError, parse_lines, parse_number};
+use crate::solutions::{Reference, Solution};
+use std::str::FromStr;
+
+pub fn solve_1(rotations: &[&str]) -> Result<u64, ParseError> {
+    Day01::part_1(&parse_lines(rotations)?)
+}
+
+pub fn solve_2(rotations: &[&str]) -> Result<u64, ParseError> {
+    Day01::part_2(&parse_lines(rotations)?)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<Rotation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part_1(rotations: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let (zero_end_positions, _) = solve(rotations);
+        Ok(zero_end_positions)
+    }
+
+    fn part_2(rotations: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let (_, zero_positions) = solve(rotations);
+        Ok(zero_positions)
+    }
+}
+
+impl Reference for Day01 {
+    fn reference_1(rotations: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let (zero_end_positions, _) = step_by_step(rotations);
+        Ok(zero_end_positions)
+    }
+
+    fn reference_2(rotations: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let (_, zero_positions) = step_by_step(rotations);
+        Ok(zero_positions)
+    }
+}
+
+const DIAL_SIZE: u32 = 100;
+
+/// Counts the times the dial ends at, and passes, 0 per rotation at once, rather than click by click
+fn solve(rotations: &[Rotation]) -> (u64, u64) {
+    let mut position = 50;
+    let mut zero_positions = 0;
+    let mut zero_end_positions = 0;
+
+    for rotation in rotations {
+        let distance = rotation.distance;
+        // Clicks needed to first reach 0, a full turn when already there
+        let to_zero = match (rotation.direction, position) {
+            (_, 0) => DIAL_SIZE,
+            (Direction::Left, _) => position,
+            (Direction::Right, _) => DIAL_SIZE - position,
+        };
+        if distance >= to_zero {
+            zero_positions += u64::from(1 + (distance - to_zero) / DIAL_SIZE);
+        }
+
+        position = match rotation.direction {
+            Direction::Left => (position + DIAL_SIZE - distance % DIAL_SIZE) % DIAL_SIZE,
+            Direction::Right => (position + distance % DIAL_SIZE) % DIAL_SIZE,
+        };
+        if position == 0 {
+            zero_end_positions += 1;
+        }
+    }
+
+    (zero_end_positions, zero_positions)
+}
+
+/// Turns the dial click by click, but for the full turns of a rotation, each of which passes 0 once
+fn step_by_step(rotations: &[Rotation]) -> (u64, u64) {
+    let mut position = 50;
+    let mut zero_positions = 0;
+    let mut zero_end_positions = 0;
+
+    for rotation in rotations {
+        zero_positions += u64::from(rotation.distance / DIAL_SIZE);
+
+        for _ in 0..rotation.distance % DIAL_SIZE {
+            match rotation.direction {
+                Direction::Left => {
+                    if position == 0 {
+                        position = 99;
+                    } else {
+                        position -= 1;
+                    }
+                }
+                Direction::Right => {
+                    if position == 99 {
+                        position = 0;
+                    } else {
+                        position += 1;
+                    }
+                }
+            }
+
+            if position == 0 {
+                zero_positions += 1;
+            }
+        }
+
+        if position == 0 {
+            zero_end_positions += 1;
+        }
+    }
+
+    (zero_end_positions, zero_positions)
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Rotation {
+    direction: Direction,
+    distance: u32,
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let direction = match s.get(..1) {
+            Some("L") => Direction::Left,
+            Some("R") => Direction::Right,
+            _ => {
+                return Err(ParseError::within(
+                    s,
+                    s,
+                    "a rotation starting with `L` or `R`",
+                ));
+            }
+        };
+        let distance = parse_number(s, &s[1..], "a rotation distance")?;
+
+        Ok(Self {
+            direction,
+            distance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_01_part_01_sample() {
+        let sample = vec![
+            "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
+        ];
+
+        assert_eq!(Ok(3), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_01_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 1, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_01_part_02_sample() {
+        let sample = vec![
+            "L68", "L30", "R48", "L5", "R60", "L55", "L1", "L99", "R14", "L82",
+        ];
+
+        assert_eq!(Ok(6), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_01_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 1, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_01_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(
+                2,
+                1,
+                "X30",
+                "a rotation starting with `L` or `R`"
+            )),
+            solve_1(&["L68", "X30"])
+        );
+        assert_eq!(
+            Err(ParseError::at(3, 2, "4a", "a rotation distance")),
+            solve_2(&["L68", "R30", "R4a"])
+        );
+        assert_eq!(
+            Err(ParseError::at(1, 2, "4294967296", "a rotation distance")),
+            solve_2(&["L4294967296"])
+        );
+    }
+
+    #[test]
+    fn day_01_long_rotations() {
+        let rotations = vec!["R999999", "L1000000", "R1000000"];
+
+        // Passing 0 10000 times each, but always ending at 49
+        assert_eq!(Ok(0), solve_1(&rotations));
+        assert_eq!(Ok(30_000), solve_2(&rotations));
+        assert_eq!(
+            step_by_step(&parse_lines(&rotations).unwrap()),
+            solve(&parse_lines(&rotations).unwrap())
+        );
+
+        let rotations = vec!["L4294967295", "R4294967295"];
+
+        assert_eq!(Ok(0), solve_1(&rotations));
+        assert_eq!(Ok(85_899_346), solve_2(&rotations));
+        assert_eq!(
+            step_by_step(&parse_lines(&rotations).unwrap()),
+            solve(&parse_lines(&rotations).unwrap())
+        );
+    }
+
+    #[test]
+    fn day_01_reference() {
+        reference::differential::<Day01>(2025, 1, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::parse::{ParseError, parse_number, parse_within};
+use crate::solutions::{Reference, Solution};
+use aoc_util::gcd;
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::str::FromStr;
+
+pub fn solve_1(id_ranges: &str) -> Result<u64, ParseError> {
+    Day02::part_1(&Day02::parse(id_ranges)?)
+}
+
+pub fn solve_2(id_ranges: &str) -> Result<u64, ParseError> {
+    Day02::part_2(&Day02::parse(id_ranges)?)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<IdRange>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let id_ranges = input.trim();
+        id_ranges
+            .split(",")
+            .map(|id_range| parse_within::<IdRange>(id_range, id_ranges))
+            .collect()
+    }
+
+    fn part_1(id_ranges: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve(id_ranges, |repeats| repeats == 2)
+    }
+
+    fn part_2(id_ranges: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve(id_ranges, |repeats| repeats >= 2)
+    }
+}
+
+impl Reference for Day02 {
+    const SEPARATOR: &'static str = ",";
+
+    fn reference_1(id_ranges: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let is_invalid_simple =
+            |id: &str| id.len().is_multiple_of(2) && id[..id.len() / 2] == id[id.len() / 2..];
+        Ok(check_every_id(id_ranges, is_invalid_simple))
+    }
+
+    fn reference_2(id_ranges: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let is_invalid_complex = |id: &str| {
+            (1..=id.len() / 2)
+                .filter(|&rep_len| id.len().is_multiple_of(rep_len))
+                .any(|rep_len| {
+                    let id = id.as_bytes();
+                    let first = &id[..rep_len];
+                    id[rep_len..].chunks(rep_len).all(|chunk| first == chunk)
+                })
+        };
+        Ok(check_every_id(id_ranges, is_invalid_complex))
+    }
+}
+
+/// Sums the invalid IDs, made of a block of digits repeated a number of times accepted by `repeats`.
+/// Rather than checking every ID in a range, the blocks that fit it are derived from its bounds directly:
+/// repeating a block of `b` digits `r` times is the same as multiplying it by `1` followed `r - 1` times by `0..01`,
+/// so the IDs made of blocks of `b` digits form an arithmetic series.
+/// An ID made of blocks of several lengths is also made of blocks of their greatest common divisor,
+/// so summing the series of every set of block lengths with alternating signs counts each ID once.
+fn solve(id_ranges: &[IdRange], repeats: impl Fn(u32) -> bool + Sync) -> Result<u64, ParseError> {
+    id_ranges
+        .par_iter()
+        .map(|&IdRange { start, end }| {
+            let (start, end) = (u128::from(start), u128::from(end));
+            let mut sum = 0i128;
+
+            for digits in 2..=u64::MAX.ilog10() + 1 {
+                let low = start.max(10u128.pow(digits - 1));
+                let high = end.min(10u128.pow(digits) - 1);
+                if low > high {
+                    continue;
+                }
+
+                let blocks = (1..=digits / 2)
+                    .filter(|&block| digits.is_multiple_of(block) && repeats(digits / block))
+                    .collect_vec();
+                for subset in 1..1u32 << blocks.len() {
+                    let block = (0..blocks.len())
+                        .filter(|idx| subset >> idx & 1 == 1)
+                        .map(|idx| u64::from(blocks[idx]))
+                        .reduce(gcd)
+                        .unwrap();
+                    let series = repeated_ids_sum(block as u32, digits, low, high) as i128;
+                    match subset.count_ones() % 2 {
+                        1 => sum += series,
+                        _ => sum -= series,
+                    }
+                }
+            }
+
+            u64::try_from(sum).ok()
+        })
+        .collect::<Option<Vec<_>>>()
+        .and_then(|sums| sums.into_iter().try_fold(0u64, u64::checked_add))
+        .ok_or_else(|| {
+            ParseError::unsolvable_input("invalid IDs adding up to fewer than fit in 64 bits")
+        })
+}
+
+/// Sum of the IDs of `digits` digits from `low` to `high`, made of a block of `block` digits repeated
+fn repeated_ids_sum(block: u32, digits: u32, low: u128, high: u128) -> u128 {
+    let multiplier = (10u128.pow(digits) - 1) / (10u128.pow(block) - 1);
+    let first = low.div_ceil(multiplier).max(10u128.pow(block - 1));
+    let last = (high / multiplier).min(10u128.pow(block) - 1);
+
+    match first <= last {
+        true => multiplier * (first + last) * (last - first + 1) / 2,
+        false => 0,
+    }
+}
+
+fn check_every_id(id_ranges: &[IdRange], is_invalid: impl Fn(&str) -> bool + Sync) -> u64 {
+    id_ranges
+        .par_iter()
+        .flat_map_iter(|&IdRange { start, end }| {
+            (start..=end).filter(|product_id| {
+                let id = product_id.to_string();
+                is_invalid(&id)
+            })
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct IdRange {
+    start: u64,
+    end: u64,
+}
+
+impl FromStr for IdRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once("-")
+            .ok_or_else(|| ParseError::within(s, s, "an ID range like `11-22`"))?;
+
+        Ok(Self {
+            start: parse_number(s, start, "a product ID")?,
+            end: parse_number(s, end, "a product ID")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_02_part_01_sample() {
+        let sample = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+            824824821-824824827,2121212118-2121212124";
+
+        assert_eq!(Ok(1_227_775_554), solve_1(sample));
+    }
+
+    #[test]
+    fn day_02_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 2, 1) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_1(input.trim()));
+    }
+
+    #[test]
+    fn day_02_part_02_sample() {
+        let sample = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+            1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+            824824821-824824827,2121212118-2121212124";
+
+        assert_eq!(Ok(4_174_379_265), solve_2(sample));
+    }
+
+    #[test]
+    fn day_02_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 2, 2) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_2(input.trim()));
+    }
+
+    #[test]
+    fn day_02_wide_ranges() {
+        let id_ranges = Day02::parse("1-99999,100000-123456").unwrap();
+
+        assert_eq!(Day02::reference_1(&id_ranges), Day02::part_1(&id_ranges));
+        assert_eq!(Day02::reference_2(&id_ranges), Day02::part_2(&id_ranges));
+
+        let expected = "invalid IDs adding up to fewer than fit in 64 bits";
+        assert_eq!(
+            Err(ParseError::unsolvable_input(expected)),
+            solve_1("0-18446744073709551615")
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable_input(expected)),
+            solve_2("1-9,10000000000000000000-18446744073709551615")
+        );
+    }
+
+    #[test]
+    fn day_02_reference() {
+        reference::differential::<Day02>(2025, 2, 25)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
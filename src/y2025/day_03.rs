@@ -0,0 +1,209 @@
+use crate::parse::{ParseError, parse_lines};
+use crate::solutions::{Reference, Solution};
+use aoc_util::BASE_10;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub fn solve_1(banks: &[&str]) -> Result<u64, ParseError> {
+    Day03::part_1(&parse_lines(banks)?)
+}
+
+pub fn solve_2(banks: &[&str]) -> Result<u64, ParseError> {
+    Day03::part_2(&parse_lines(banks)?)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Bank>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(&input.lines().collect::<Vec<_>>())
+    }
+
+    fn part_1(banks: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve(banks, 2)
+    }
+
+    fn part_2(banks: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve(banks, 12)
+    }
+}
+
+impl Reference for Day03 {
+    fn reference_1(banks: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve_with(banks, 2, Bank::max_joltage_exhaustive)
+    }
+
+    fn reference_2(banks: &Self::Input<'_>) -> Result<u64, ParseError> {
+        solve_with(banks, 12, Bank::max_joltage_exhaustive)
+    }
+}
+
+fn solve(banks: &[Bank], batteries_count: usize) -> Result<u64, ParseError> {
+    solve_with(banks, batteries_count, Bank::max_joltage)
+}
+
+fn solve_with(
+    banks: &[Bank],
+    batteries_count: usize,
+    max_joltage: fn(&Bank, usize) -> u64,
+) -> Result<u64, ParseError> {
+    if let Some(idx) = banks
+        .iter()
+        .position(|bank| bank.batteries.len() < batteries_count)
+    {
+        let expected = "a bank with enough batteries to turn on";
+        return Err(ParseError::unsolvable(
+            idx + 1,
+            1,
+            &banks[idx].to_string(),
+            expected,
+        ));
+    }
+
+    Ok(banks
+        .iter()
+        .map(|bank| max_joltage(bank, batteries_count))
+        .sum())
+}
+
+#[derive(Debug, Clone)]
+pub struct Bank {
+    batteries: Vec<u64>,
+}
+
+impl FromStr for Bank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let batteries = s
+            .char_indices()
+            .map(|(idx, battery)| {
+                battery.to_digit(BASE_10).map(u64::from).ok_or_else(|| {
+                    let battery = &s[idx..idx + battery.len_utf8()];
+                    ParseError::within(s, battery, "a battery joltage digit")
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { batteries })
+    }
+}
+
+impl Display for Bank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.batteries
+            .iter()
+            .try_for_each(|battery| write!(f, "{battery}"))
+    }
+}
+
+impl Bank {
+    pub fn max_joltage(&self, batteries_count: usize) -> u64 {
+        let mut joltage = 0;
+        let mut start_idx = 0;
+
+        for end_idx in self.batteries.len() - batteries_count + 1..=self.batteries.len() {
+            let (idx, battery) = self.batteries[start_idx..end_idx]
+                .iter()
+                .enumerate()
+                .max_by(|(idx_1, bat_1), (idx_2, bat_2)| bat_1.cmp(bat_2).then(idx_2.cmp(idx_1)))
+                .map(|(idx, &bat)| (idx, bat))
+                .unwrap();
+
+            joltage = joltage * 10 + battery;
+            start_idx += idx + 1;
+        }
+
+        joltage
+    }
+
+    /// Considers every battery for every position instead of greedily picking the largest one,
+    /// keeping the highest joltage of each length turned on so far
+    pub fn max_joltage_exhaustive(&self, batteries_count: usize) -> u64 {
+        let mut best: Vec<Option<u64>> = vec![None; batteries_count + 1];
+        best[0] = Some(0);
+
+        for &battery in &self.batteries {
+            for count in (1..=batteries_count).rev() {
+                if let Some(joltage) = best[count - 1] {
+                    let joltage = joltage * 10 + battery;
+                    best[count] = best[count].max(Some(joltage));
+                }
+            }
+        }
+
+        best[batteries_count].unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_03_part_01_sample() {
+        let sample = vec![
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+
+        assert_eq!(Ok(357), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_03_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 3, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_03_part_02_sample() {
+        let sample = vec![
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+
+        assert_eq!(Ok(3_121_910_778_619), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_03_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 3, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_03_too_few_batteries() {
+        let expected = "a bank with enough batteries to turn on";
+
+        assert_eq!(Ok(12), solve_1(&["12"]));
+        assert_eq!(
+            Err(ParseError::unsolvable(2, 1, "123", expected)),
+            solve_2(&["123456789123", "123"])
+        );
+    }
+
+    #[test]
+    fn day_03_reference() {
+        reference::differential::<Day03>(2025, 3, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::parse::ParseError;
+use crate::solutions::{Reference, Solution};
+use rustc_hash::FxHashSet;
+
+pub fn solve_1(department: &[&str]) -> Result<usize, ParseError> {
+    Day04::part_1(&Department::try_from(department)?)
+}
+
+pub fn solve_2(department: &[&str]) -> Result<usize, ParseError> {
+    Day04::part_2(&Department::try_from(department)?)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Department;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Department::try_from(input.lines().collect::<Vec<_>>().as_slice())
+    }
+
+    fn part_1(department: &Self::Input<'_>) -> Result<usize, ParseError> {
+        let (_, rolls_removed) = department.remove_paper_rolls();
+        Ok(rolls_removed)
+    }
+
+    fn part_2(department: &Self::Input<'_>) -> Result<usize, ParseError> {
+        let mut department = department.clone();
+        let mut total_rolls_removed = 0;
+
+        loop {
+            let (new_department, rolls_removed) = department.remove_paper_rolls();
+
+            if rolls_removed == 0 {
+                return Ok(total_rolls_removed);
+            }
+
+            department = new_department;
+            total_rolls_removed += rolls_removed;
+        }
+    }
+}
+
+impl Reference for Day04 {
+    fn reference_1(department: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(accessible_rolls(&department.grid()).len())
+    }
+
+    fn reference_2(department: &Self::Input<'_>) -> Result<usize, ParseError> {
+        let mut grid = department.grid();
+        let mut total_rolls_removed = 0;
+
+        // Removing a roll only ever makes others accessible, so the order they are removed in does not matter
+        while let Some(&(x, y)) = accessible_rolls(&grid).first() {
+            grid[y][x] = false;
+            total_rolls_removed += 1;
+        }
+
+        Ok(total_rolls_removed)
+    }
+}
+
+/// Positions `(x, y)` of the paper rolls with fewer than 4 of their 8 neighbours taken by another roll
+fn accessible_rolls(grid: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let is_roll = |x: Option<usize>, y: Option<usize>| {
+        x.zip(y)
+            .and_then(|(x, y)| grid.get(y).and_then(|row| row.get(x)))
+            .copied()
+            .unwrap_or(false)
+    };
+
+    let mut accessible = vec![];
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &roll) in row.iter().enumerate() {
+            if !roll {
+                continue;
+            }
+
+            let mut neighbours = 0;
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if (dx, dy) != (0, 0)
+                        && is_roll(x.checked_add_signed(dx), y.checked_add_signed(dy))
+                    {
+                        neighbours += 1;
+                    }
+                }
+            }
+            if neighbours < 4 {
+                accessible.push((x, y));
+            }
+        }
+    }
+
+    accessible
+}
+
+#[derive(Debug, Clone)]
+pub struct Department {
+    paper_rolls: FxHashSet<Position>,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl TryFrom<&[&str]> for Department {
+    type Error = ParseError;
+
+    fn try_from(grid: &[&str]) -> Result<Self, Self::Error> {
+        let mut paper_rolls = FxHashSet::default();
+
+        for (y, line) in grid.iter().enumerate() {
+            for (x, (idx, c)) in line.char_indices().enumerate() {
+                match c {
+                    '@' => {
+                        paper_rolls.insert(Position {
+                            x: x as i32,
+                            y: y as i32,
+                        });
+                    }
+                    '.' => {}
+                    _ => {
+                        let text = &line[idx..idx + c.len_utf8()];
+                        return Err(ParseError::at(y + 1, x + 1, text, "`@` or `.`"));
+                    }
+                }
+            }
+        }
+
+        Ok(Self { paper_rolls })
+    }
+}
+
+impl Department {
+    /// Paper rolls as rows of cells, just wide and high enough to hold every roll
+    fn grid(&self) -> Vec<Vec<bool>> {
+        let width = self
+            .paper_rolls
+            .iter()
+            .map(|roll| roll.x + 1)
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .paper_rolls
+            .iter()
+            .map(|roll| roll.y + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut grid = vec![vec![false; width as usize]; height as usize];
+        for roll in &self.paper_rolls {
+            grid[roll.y as usize][roll.x as usize] = true;
+        }
+        grid
+    }
+
+    pub fn remove_paper_rolls(&self) -> (Self, usize) {
+        let neighbour_deltas = [
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+        ];
+
+        let paper_rolls = self
+            .paper_rolls
+            .iter()
+            .copied()
+            .filter(|Position { x, y }| {
+                neighbour_deltas
+                    .iter()
+                    .filter(|(dx, dy)| {
+                        let neighbour = Position {
+                            x: x + dx,
+                            y: y + dy,
+                        };
+                        self.paper_rolls.contains(&neighbour)
+                    })
+                    .count()
+                    >= 4
+            })
+            .collect::<FxHashSet<_>>();
+        let old_rolls_count = self.paper_rolls.len();
+        let new_rolls_count = paper_rolls.len();
+
+        (Self { paper_rolls }, old_rolls_count - new_rolls_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_04_part_01_sample() {
+        let sample = vec![
+            "..@@.@@@@.",
+            "@@@.@.@.@@",
+            "@@@@@.@.@@",
+            "@.@@@@..@.",
+            "@@.@@@@.@@",
+            ".@@@@@@@.@",
+            ".@.@.@.@@@",
+            "@.@@@.@@@@",
+            ".@@@@@@@@.",
+            "@.@.@@@.@.",
+        ];
+
+        assert_eq!(Ok(13), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_04_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 4, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_04_part_02_sample() {
+        let sample = vec![
+            "..@@.@@@@.",
+            "@@@.@.@.@@",
+            "@@@@@.@.@@",
+            "@.@@@@..@.",
+            "@@.@@@@.@@",
+            ".@@@@@@@.@",
+            ".@.@.@.@@@",
+            "@.@@@.@@@@",
+            ".@@@@@@@@.",
+            "@.@.@@@.@.",
+        ];
+
+        assert_eq!(Ok(43), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_04_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 4, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_04_reference() {
+        reference::differential::<Day04>(2025, 4, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,289 @@
+use crate::parse::{ParseError, parse_number, parse_within};
+use crate::solutions::{Reference, Solution};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub fn solve_1(database: &str) -> Result<usize, ParseError> {
+    Day05::part_1(&Day05::parse(database)?)
+}
+
+pub fn solve_2(database: &str) -> Result<u64, ParseError> {
+    Day05::part_2(&Day05::parse(database)?)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Database;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.trim().parse()
+    }
+
+    fn part_1(database: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(database
+            .ingredients
+            .iter()
+            .filter(|ingredient| {
+                database
+                    .fresh_ranges
+                    .iter()
+                    .any(|range| range.contains(ingredient))
+            })
+            .count())
+    }
+
+    fn part_2(database: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let fresh_ids = database.fresh_ranges.iter().map(FreshRange::len).sum();
+        fits_answer(fresh_ids)
+    }
+}
+
+impl Reference for Day05 {
+    fn reference_1(database: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(database
+            .ingredients
+            .iter()
+            .filter(|ingredient| {
+                database
+                    .listed_ranges
+                    .iter()
+                    .any(|range| range.contains(ingredient))
+            })
+            .count())
+    }
+
+    /// Sweeps over the bounds of the ranges as listed, counting the IDs in between while inside any of them
+    fn reference_2(database: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let bounds = database
+            .listed_ranges
+            .iter()
+            .flat_map(|range| {
+                [
+                    (u128::from(range.start), 1),
+                    (u128::from(range.end) + 1, -1),
+                ]
+            })
+            .sorted()
+            .collect_vec();
+
+        let mut fresh_ids = 0;
+        let mut overlapping = 0;
+        for window in bounds.windows(2) {
+            let ((id, change), (next_id, _)) = (window[0], window[1]);
+            overlapping += change;
+            if overlapping > 0 {
+                fresh_ids += next_id - id;
+            }
+        }
+
+        fits_answer(fresh_ids)
+    }
+}
+
+/// Every ID from 0 up to `u64::MAX` being fresh would be one too many for the answer
+fn fits_answer(fresh_ids: u128) -> Result<u64, ParseError> {
+    u64::try_from(fresh_ids)
+        .map_err(|_| ParseError::unsolvable_input("fewer fresh IDs than fit in 64 bits"))
+}
+
+type Ingredient = u64;
+
+#[derive(Debug, Clone)]
+pub struct Database {
+    fresh_ranges: Vec<FreshRange>,
+    /// Fresh ranges as listed, before merging
+    listed_ranges: Vec<FreshRange>,
+    ingredients: Vec<Ingredient>,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct FreshRange {
+    start: Ingredient,
+    end: Ingredient,
+}
+
+impl FromStr for Database {
+    type Err = ParseError;
+
+    fn from_str(database: &str) -> Result<Self, Self::Err> {
+        let (fresh_ranges, ingredients) = database.split_once("\n\n").ok_or_else(|| {
+            let expected = "fresh ranges and ingredients separated by a blank line";
+            ParseError::within(database, database, expected)
+        })?;
+
+        let listed_ranges = fresh_ranges
+            .lines()
+            .map(|fresh_range| parse_within::<FreshRange>(fresh_range, database))
+            .collect::<Result<Vec<_>, _>>()?;
+        let fresh_ranges = listed_ranges
+            .iter()
+            .copied()
+            .sorted_by_key(|range| range.start)
+            .collect_vec();
+
+        let mut merged_ranges = vec![];
+        let Some(&(mut current_range)) = fresh_ranges.first() else {
+            return Err(ParseError::within(database, database, "a fresh range"));
+        };
+
+        for range in fresh_ranges.iter().skip(1) {
+            let merged = current_range.sorted_merge(range);
+            match merged {
+                Some(merged) => current_range = merged,
+                None => {
+                    merged_ranges.push(current_range);
+                    current_range = *range;
+                }
+            }
+        }
+        merged_ranges.push(current_range);
+
+        let ingredients = ingredients
+            .lines()
+            .map(|ingredient| parse_number(database, ingredient, "an ingredient ID"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            fresh_ranges: merged_ranges,
+            listed_ranges,
+            ingredients,
+        })
+    }
+}
+
+impl FromStr for FreshRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once("-")
+            .ok_or_else(|| ParseError::within(s, s, "a fresh range like `3-5`"))?;
+
+        let range = Self {
+            start: parse_number(s, start, "an ingredient ID")?,
+            end: parse_number(s, end, "an ingredient ID")?,
+        };
+        if range.end < range.start {
+            return Err(ParseError::within(
+                s,
+                end,
+                "an ingredient ID not below the start",
+            ));
+        }
+
+        Ok(range)
+    }
+}
+
+impl FreshRange {
+    pub fn sorted_merge(&self, other: &Self) -> Option<Self> {
+        if other.start <= self.end {
+            Some(Self {
+                start: self.start,
+                end: self.end.max(other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, ingredient: &Ingredient) -> bool {
+        ingredient >= &self.start && ingredient <= &self.end
+    }
+
+    pub fn len(&self) -> u128 {
+        u128::from(self.end - self.start) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_05_part_01_sample() {
+        let sample = "\
+            3-5\n\
+            10-14\n\
+            16-20\n\
+            12-18\n\
+            \n\
+            1\n\
+            5\n\
+            8\n\
+            11\n\
+            17\n\
+            32\
+        ";
+
+        assert_eq!(Ok(3), solve_1(sample));
+    }
+
+    #[test]
+    fn day_05_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 5, 1) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_1(input.trim()));
+    }
+
+    #[test]
+    fn day_05_part_02_sample() {
+        let sample = "\
+            3-5\n\
+            10-14\n\
+            16-20\n\
+            12-18\n\
+            \n\
+            1\n\
+            5\n\
+            8\n\
+            11\n\
+            17\n\
+            32\
+        ";
+
+        assert_eq!(Ok(14), solve_2(sample));
+    }
+
+    #[test]
+    fn day_05_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 5, 2) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_2(input.trim()));
+    }
+
+    #[test]
+    fn day_05_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(
+                2,
+                3,
+                "3",
+                "an ingredient ID not below the start"
+            )),
+            solve_2("1-2\n5-3\n\n1")
+        );
+        assert_eq!(Ok(1), solve_2("5-5\n\n1"));
+        assert_eq!(
+            Err(ParseError::unsolvable_input(
+                "fewer fresh IDs than fit in 64 bits"
+            )),
+            solve_2("0-18446744073709551615\n\n1")
+        );
+        assert_eq!(Ok(u64::MAX), solve_2("1-18446744073709551615\n\n1"));
+    }
+
+    #[test]
+    fn day_05_reference() {
+        reference::differential::<Day05>(2025, 5, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
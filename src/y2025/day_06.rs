@@ -0,0 +1,355 @@
+use crate::parse::ParseError;
+use crate::solutions::{Reference, Solution};
+use itertools::Itertools;
+use std::ops::Range;
+
+pub fn solve_1(homework: &[&str]) -> Result<u64, ParseError> {
+    Day06::part_1(&Homework::try_from(homework)?)
+}
+
+pub fn solve_2(homework: &[&str]) -> Result<u64, ParseError> {
+    Day06::part_2(&Homework::try_from(homework)?)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Homework;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Homework::try_from(input.lines().collect_vec().as_slice())
+    }
+
+    fn part_1(homework: &Self::Input<'_>) -> Result<u64, ParseError> {
+        homework.solve(Problem::classic_math)
+    }
+
+    fn part_2(homework: &Self::Input<'_>) -> Result<u64, ParseError> {
+        homework.solve(Problem::cephalopod_math)
+    }
+}
+
+impl Reference for Day06 {
+    /// Splits every row of numbers on whitespace, the n-th number of each row belonging to the n-th problem
+    fn reference_1(homework: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let rows = homework
+            .numbers
+            .iter()
+            .enumerate()
+            .map(|(row, line)| {
+                let line = line.iter().collect::<String>();
+                line.split_whitespace()
+                    .map(|number| {
+                        number.parse().map_err(|_| {
+                            ParseError::within(&line, number, "a number").shift_lines(row)
+                        })
+                    })
+                    .collect::<Result<Vec<u64>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        homework.total(
+            homework
+                .columns
+                .iter()
+                .enumerate()
+                .map(|(idx, &(_, operation))| {
+                    let numbers = rows
+                        .iter()
+                        .filter_map(|row| row.get(idx).copied())
+                        .collect();
+                    Problem { numbers, operation }
+                }),
+        )
+    }
+
+    /// Reads the digits of every column top to bottom, a column without any ending the problem
+    fn reference_2(homework: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let width = homework.numbers.iter().map(Vec::len).max().unwrap_or(0);
+        let mut problems: Vec<Vec<u64>> = vec![vec![]];
+
+        for x in 0..width {
+            let digits = homework
+                .numbers
+                .iter()
+                .filter_map(|line| line.get(x))
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>();
+
+            match digits.is_empty() {
+                true => problems.push(vec![]),
+                false => {
+                    let number = digits
+                        .parse()
+                        .map_err(|_| ParseError::at(1, x + 1, &digits, "a number"))?;
+                    problems.last_mut().unwrap().push(number);
+                }
+            }
+        }
+
+        homework.total(
+            homework
+                .columns
+                .iter()
+                .zip(problems)
+                .map(|(&(_, operation), numbers)| Problem { numbers, operation }),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Homework {
+    numbers: Vec<Vec<char>>,
+    columns: Vec<(Range<usize>, Operation)>,
+}
+
+impl TryFrom<&[&str]> for Homework {
+    type Error = ParseError;
+
+    fn try_from(homework: &[&str]) -> Result<Self, Self::Error> {
+        let homework = homework
+            .iter()
+            .map(|line| line.chars().collect_vec())
+            .collect_vec();
+
+        let Some((operators, numbers)) = homework.split_last() else {
+            return Err(ParseError::at(1, 1, "", "a homework sheet"));
+        };
+        let operators = (0..operators.len())
+            .filter(|&idx| operators[idx] != ' ')
+            .map(|idx| match operators[idx] {
+                '+' => Ok((idx, Operation::Addition)),
+                '*' => Ok((idx, Operation::Multiplication)),
+                c => {
+                    let expected = "an operation `+` or `*`";
+                    Err(ParseError::at(
+                        homework.len(),
+                        idx + 1,
+                        &c.to_string(),
+                        expected,
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let columns = (0..operators.len())
+            .map(|idx| {
+                let (start, operation) = operators[idx];
+                let end = if idx < operators.len() - 1 {
+                    operators[idx + 1].0 - 1
+                } else {
+                    homework[0].len()
+                };
+
+                (start..end, operation)
+            })
+            .collect_vec();
+
+        for (Range { start, end }, _) in &columns {
+            for (row, line) in numbers.iter().enumerate() {
+                if line.get(*start..*end).is_none() {
+                    let text = line.iter().skip(*start).collect::<String>();
+                    let expected = "a line as wide as the first one";
+                    return Err(ParseError::at(row + 1, start + 1, &text, expected));
+                }
+            }
+        }
+
+        Ok(Self {
+            numbers: numbers.to_vec(),
+            columns,
+        })
+    }
+}
+
+impl Homework {
+    fn solve(
+        &self,
+        math: impl Fn(&[&[char]], usize, Operation) -> Result<Problem, ParseError>,
+    ) -> Result<u64, ParseError> {
+        let problems = self
+            .columns
+            .iter()
+            .map(|(range, operation)| {
+                let numbers = self
+                    .numbers
+                    .iter()
+                    .map(|line| &line[range.clone()])
+                    .collect_vec();
+                math(&numbers, range.start, *operation)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.total(problems)
+    }
+
+    /// Sums the results of the problems, one per column, reporting the first one that overflows at its operation
+    fn total(&self, problems: impl IntoIterator<Item = Problem>) -> Result<u64, ParseError> {
+        self.columns
+            .iter()
+            .zip(problems)
+            .try_fold(0u64, |total, ((range, operation), problem)| {
+                problem
+                    .solve()
+                    .and_then(|result| total.checked_add(result))
+                    .ok_or_else(|| {
+                        let expected =
+                            "a problem whose result, added to the others, fits in 64 bits";
+                        let line = self.numbers.len() + 1;
+                        ParseError::unsolvable(line, range.start + 1, operation.symbol(), expected)
+                    })
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Problem {
+    numbers: Vec<u64>,
+    operation: Operation,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Operation {
+    Addition,
+    Multiplication,
+}
+
+impl Problem {
+    pub fn classic_math(
+        numbers: &[&[char]],
+        column: usize,
+        operation: Operation,
+    ) -> Result<Self, ParseError> {
+        let numbers = numbers
+            .iter()
+            .enumerate()
+            .map(|(row, number)| {
+                let number = number.iter().collect::<String>();
+                number
+                    .trim()
+                    .parse()
+                    .map_err(|_| ParseError::at(row + 1, column + 1, &number, "a number"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { numbers, operation })
+    }
+
+    pub fn cephalopod_math(
+        numbers: &[&[char]],
+        column: usize,
+        operation: Operation,
+    ) -> Result<Self, ParseError> {
+        let numbers = (0..numbers.first().map_or(0, |line| line.len()))
+            .map(|idx| {
+                let number = numbers.iter().map(|line| line[idx]).collect::<String>();
+                number
+                    .trim()
+                    .parse()
+                    .map_err(|_| ParseError::at(1, column + idx + 1, &number, "a number"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { numbers, operation })
+    }
+
+    /// Result of the problem, `None` if it does not fit in 64 bits
+    pub fn solve(&self) -> Option<u64> {
+        self.numbers.iter().try_fold(
+            match self.operation {
+                Operation::Addition => 0,
+                Operation::Multiplication => 1,
+            },
+            |acc: u64, &number| match self.operation {
+                Operation::Addition => acc.checked_add(number),
+                Operation::Multiplication => acc.checked_mul(number),
+            },
+        )
+    }
+}
+
+impl Operation {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Addition => "+",
+            Operation::Multiplication => "*",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_06_part_01_sample() {
+        let sample = vec![
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ];
+
+        assert_eq!(Ok(4_277_556), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_06_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 6, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_06_part_02_sample() {
+        let sample = vec![
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ];
+
+        assert_eq!(Ok(3_263_827), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_06_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 6, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_06_overflow() {
+        let expected = "a problem whose result, added to the others, fits in 64 bits";
+        let homework = vec!["1 5000000000", "2 5000000000", "+ *         "];
+
+        assert_eq!(
+            Err(ParseError::unsolvable(3, 3, "*", expected)),
+            solve_1(&homework)
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable(3, 1, "+", expected)),
+            solve_1(&["18446744073709551615", "                   1", "+"])
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable(3, 3, "+", expected)),
+            solve_1(&["1 18446744073709551615", "2                    0", "* +"])
+        );
+    }
+
+    #[test]
+    fn day_06_reference() {
+        reference::differential::<Day06>(2025, 6, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,320 @@
+use crate::parse::ParseError;
+use crate::solutions::{Reference, Solution};
+use aoc_util::a_star::Graphable;
+use aoc_util::search::bfs;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub fn solve_1(manifold: &[&str]) -> Result<usize, ParseError> {
+    Day07::part_1(&Manifold::try_from(manifold)?)
+}
+
+pub fn solve_2(manifold: &[&str]) -> Result<u64, ParseError> {
+    Day07::part_2(&Manifold::try_from(manifold)?)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Manifold;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Manifold::try_from(input.lines().collect::<Vec<_>>().as_slice())
+    }
+
+    fn part_1(manifold: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(manifold.beam_splits())
+    }
+
+    fn part_2(manifold: &Self::Input<'_>) -> Result<u64, ParseError> {
+        Ok(manifold.timeline_splits())
+    }
+}
+
+impl Reference for Day07 {
+    fn reference_1(manifold: &Self::Input<'_>) -> Result<usize, ParseError> {
+        let (splitters_hit, _) = manifold.row_by_row();
+        Ok(splitters_hit)
+    }
+
+    fn reference_2(manifold: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let (_, timelines) = manifold.row_by_row();
+        Ok(timelines)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    entry: Position,
+    splitters: FxHashSet<Position>,
+    depth: usize,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl TryFrom<&[&str]> for Manifold {
+    type Error = ParseError;
+
+    fn try_from(manifold: &[&str]) -> Result<Self, Self::Error> {
+        let first_line = manifold.first().copied().unwrap_or_default();
+        let entry = Position {
+            x: first_line
+                .chars()
+                .position(|c| c == 'S')
+                .ok_or_else(|| ParseError::at(1, 1, first_line, "an entry `S`"))?,
+            y: 0,
+        };
+        let mut splitters = FxHashSet::default();
+
+        for (y, line) in manifold.iter().enumerate() {
+            for (x, (idx, c)) in line.char_indices().enumerate() {
+                match c {
+                    // A beam split there would leave the manifold on the left
+                    '^' if x == 0 => {
+                        return Err(ParseError::at(y + 1, 1, "^", "`.` or `S` on the left edge"));
+                    }
+                    // Beams would keep bouncing between both splitters
+                    '^' if splitters.contains(&Position::of(x - 1, y)) => {
+                        return Err(ParseError::at(
+                            y + 1,
+                            x + 1,
+                            "^",
+                            "`.` or `S` beside a splitter",
+                        ));
+                    }
+                    '^' => {
+                        splitters.insert(Position { x, y });
+                    }
+                    '.' | 'S' => {}
+                    _ => {
+                        let text = &line[idx..idx + c.len_utf8()];
+                        return Err(ParseError::at(y + 1, x + 1, text, "`.`, `S` or `^`"));
+                    }
+                }
+            }
+        }
+
+        let depth = manifold.len();
+
+        Ok(Self {
+            entry,
+            splitters,
+            depth,
+        })
+    }
+}
+
+impl Manifold {
+    pub fn beam_splits(&self) -> usize {
+        bfs([self.entry], self)
+            .distances
+            .keys()
+            .filter(|position| self.splitters.contains(position))
+            .count()
+    }
+
+    /// Counts the timelines from every position reachable from the entry, each one after the positions it leads to,
+    /// found depth-first with an explicit stack as the manifold can be far too deep to recurse through
+    pub fn timeline_splits(&self) -> u64 {
+        let mut timelines_at: FxHashMap<Position, u64> = FxHashMap::default();
+        let mut stack = vec![self.entry];
+
+        while let Some(&position) = stack.last() {
+            if timelines_at.contains_key(&position) {
+                stack.pop();
+                continue;
+            }
+
+            let next = self.next(&position);
+            let pending = next
+                .iter()
+                .filter(|p| p.y < self.depth && !timelines_at.contains_key(p))
+                .copied()
+                .collect::<Vec<_>>();
+            if pending.is_empty() {
+                stack.pop();
+                let timelines = next
+                    .iter()
+                    .map(|p| timelines_at.get(p).copied().unwrap_or(1))
+                    .sum();
+                timelines_at.insert(position, timelines);
+            } else {
+                stack.extend(pending);
+            }
+        }
+
+        timelines_at[&self.entry]
+    }
+
+    /// Positions a beam moves on to from `position`, sideways from a splitter and down otherwise
+    fn next(&self, position: &Position) -> Vec<Position> {
+        if self.splitters.contains(position) {
+            vec![
+                Position::of(position.x - 1, position.y),
+                Position::of(position.x + 1, position.y),
+            ]
+        } else {
+            vec![Position::of(position.x, position.y + 1)]
+        }
+    }
+
+    /// Follows the beams down one row at a time, keeping the number of timelines per beam,
+    /// returning the number of splitters any beam hit and the number of timelines leaving the manifold
+    fn row_by_row(&self) -> (usize, u64) {
+        let mut splitters_hit = FxHashSet::default();
+        let mut beams = FxHashMap::from_iter([(self.entry.x, 1)]);
+
+        for y in 0..self.depth {
+            let mut pending = beams.into_iter().collect::<Vec<_>>();
+            beams = FxHashMap::default();
+
+            while let Some((x, timelines)) = pending.pop() {
+                match self.splitters.contains(&Position::of(x, y)) {
+                    true => {
+                        splitters_hit.insert(Position::of(x, y));
+                        pending.extend([(x - 1, timelines), (x + 1, timelines)]);
+                    }
+                    false => *beams.entry(x).or_default() += timelines,
+                }
+            }
+        }
+
+        (splitters_hit.len(), beams.values().sum())
+    }
+}
+
+impl Position {
+    fn of(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Graphable for Position {
+    type Context = Manifold;
+    type Cost = u32;
+
+    fn neighbors(&self, manifold: &Manifold) -> Vec<(Self, u32)> {
+        manifold
+            .next(self)
+            .into_iter()
+            .filter(|position| position.y < manifold.depth)
+            .map(|position| (position, 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_07_part_01_sample() {
+        let sample = vec![
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+            ".....^.^.^.....",
+            "...............",
+            "....^.^...^....",
+            "...............",
+            "...^.^...^.^...",
+            "...............",
+            "..^...^.....^..",
+            "...............",
+            ".^.^.^.^.^...^.",
+            "...............",
+        ];
+
+        assert_eq!(Ok(21), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_07_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 7, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_07_part_02_sample() {
+        let sample = vec![
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+            ".....^.^.^.....",
+            "...............",
+            "....^.^...^....",
+            "...............",
+            "...^.^...^.^...",
+            "...............",
+            "..^...^.....^..",
+            "...............",
+            ".^.^.^.^.^...^.",
+            "...............",
+        ];
+
+        assert_eq!(Ok(40), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_07_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 7, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_07_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(1, 1, "...", "an entry `S`")),
+            solve_1(&["...", ".^."])
+        );
+        assert_eq!(
+            Err(ParseError::at(2, 2, "x", "`.`, `S` or `^`")),
+            solve_1(&[".S.", ".x."])
+        );
+        assert_eq!(
+            Err(ParseError::at(2, 1, "^", "`.` or `S` on the left edge")),
+            solve_2(&["S", "^"])
+        );
+        assert_eq!(
+            Err(ParseError::at(3, 3, "^", "`.` or `S` beside a splitter")),
+            solve_2(&["..S..", ".....", ".^^.."])
+        );
+    }
+
+    #[test]
+    fn day_07_deep_manifold() {
+        let mut manifold = vec![".S."; 200_000];
+        manifold.push(".^.");
+
+        assert_eq!(Ok(1), solve_1(&manifold));
+        assert_eq!(Ok(2), solve_2(&manifold));
+    }
+
+    #[test]
+    fn day_07_reference() {
+        reference::differential::<Day07>(2025, 7, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
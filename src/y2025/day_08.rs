@@ -0,0 +1,367 @@
+use crate::parse::{ParseError, parse_lines, parse_number};
+use crate::solutions::{Reference, Solution};
+use aoc_util::union_find::UnionFind;
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+use rustc_hash::FxHashMap;
+use std::str::FromStr;
+
+pub fn solve_1(junctions: &[&str], nr_junctions: usize) -> Result<usize, ParseError> {
+    Ok(Day08Setup::try_from(junctions)?.largest_circuits(nr_junctions))
+}
+
+pub fn solve_2(junctions: &[&str]) -> Result<u64, ParseError> {
+    Day08::part_2(&Day08Setup::try_from(junctions)?)
+}
+
+/// The puzzle input connects the 1000 closest pairs, the sample only 10
+const NR_JUNCTIONS: usize = 1_000;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Day08Setup;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Day08Setup::try_from(input.lines().collect_vec().as_slice())
+    }
+
+    fn part_1(setup: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(setup.largest_circuits(NR_JUNCTIONS))
+    }
+
+    fn part_2(setup: &Self::Input<'_>) -> Result<u64, ParseError> {
+        Ok(setup.last_connection())
+    }
+}
+
+impl Reference for Day08 {
+    fn reference_1(setup: &Self::Input<'_>) -> Result<usize, ParseError> {
+        let mut circuits = Circuits::new(setup);
+        for (from, to) in circuits.pairs().into_iter().take(NR_JUNCTIONS) {
+            circuits.connect(from, to);
+        }
+
+        Ok(circuits
+            .labels
+            .iter()
+            .counts()
+            .into_values()
+            .sorted()
+            .rev()
+            .take(3)
+            .product())
+    }
+
+    fn reference_2(setup: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let mut circuits = Circuits::new(setup);
+        let mut nr_circuits = circuits.junctions.len();
+        for (from, to) in circuits.pairs() {
+            if circuits.connect(from, to) {
+                nr_circuits -= 1;
+            }
+            if nr_circuits == 1 {
+                let (from, to) = (circuits.junctions[from], circuits.junctions[to]);
+                return Ok(u64::from(from.x) * u64::from(to.x));
+            }
+        }
+        unreachable!()
+    }
+}
+
+/// Junction boxes labelled by the circuit they are in, relabelling a whole circuit whenever it gets connected
+struct Circuits {
+    junctions: Vec<Position>,
+    labels: Vec<usize>,
+}
+
+impl Circuits {
+    fn new(setup: &Day08Setup) -> Self {
+        let junctions = setup
+            .junctions_to_idx
+            .iter()
+            .sorted_by_key(|&(_, idx)| idx)
+            .map(|(&junction, _)| junction)
+            .collect_vec();
+        let labels = (0..junctions.len()).collect_vec();
+
+        Self { junctions, labels }
+    }
+
+    /// Every pair of junction boxes, sorted by their exact squared distance
+    fn pairs(&self) -> Vec<(usize, usize)> {
+        let squared_distance = |from: Position, to: Position| {
+            [(from.x, to.x), (from.y, to.y), (from.z, to.z)]
+                .iter()
+                .map(|&(a, b)| u128::from(a.abs_diff(b)).pow(2))
+                .sum::<u128>()
+        };
+
+        (0..self.junctions.len())
+            .tuple_combinations()
+            .sorted_by_key(|&(from, to)| squared_distance(self.junctions[from], self.junctions[to]))
+            .collect()
+    }
+
+    /// Connects the circuits of both junction boxes, returning whether they were different circuits before
+    fn connect(&mut self, from: usize, to: usize) -> bool {
+        let (from, to) = (self.labels[from], self.labels[to]);
+        if from == to {
+            return false;
+        }
+        for label in &mut self.labels {
+            if *label == to {
+                *label = from;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Position {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y, z) = s
+            .split(',')
+            .collect_tuple()
+            .ok_or_else(|| ParseError::within(s, s, "a position like `162,817,812`"))?;
+
+        Ok(Position {
+            x: parse_number(s, x, "a coordinate")?,
+            y: parse_number(s, y, "a coordinate")?,
+            z: parse_number(s, z, "a coordinate")?,
+        })
+    }
+}
+
+impl Position {
+    pub fn distance_to(&self, other: &Self) -> u64 {
+        let dx = f64::from(self.x) - f64::from(other.x);
+        let dy = f64::from(self.y) - f64::from(other.y);
+        let dz = f64::from(self.z) - f64::from(other.z);
+
+        (dx * dx + dy * dy + dz * dz)
+            .sqrt()
+            .round()
+            .to_u64()
+            .unwrap()
+    }
+}
+
+/// Junction boxes with every pair sorted by distance, computed once and shared by both parts
+#[derive(Debug, Clone)]
+pub struct Day08Setup {
+    junctions_to_idx: FxHashMap<Position, usize>,
+    pairs: Vec<(Position, Position, u64)>,
+    uf: UnionFind,
+}
+
+impl TryFrom<&[&str]> for Day08Setup {
+    type Error = ParseError;
+
+    fn try_from(lines: &[&str]) -> Result<Self, Self::Error> {
+        let junctions: Vec<Position> = parse_lines(lines)?;
+        if junctions.is_empty() {
+            return Err(ParseError::at(1, 1, "", "a junction box position"));
+        }
+        if junctions.len() == 1 {
+            return Err(ParseError::at(2, 1, "", "a second junction box position"));
+        }
+        let mut junctions_to_idx = FxHashMap::default();
+        for (idx, &junction) in junctions.iter().enumerate() {
+            if junctions_to_idx.insert(junction, idx).is_some() {
+                return Err(ParseError::at(
+                    idx + 1,
+                    1,
+                    lines[idx],
+                    "a junction box position not seen before",
+                ));
+            }
+        }
+        let pairs = junctions[..junctions.len() - 1]
+            .iter()
+            .copied()
+            .enumerate()
+            .flat_map(|(idx, junction)| {
+                junctions[idx + 1..]
+                    .iter()
+                    .map(move |&other| (junction, other, junction.distance_to(&other)))
+            })
+            .sorted_by_key(|(_, _, distance)| *distance)
+            .collect_vec();
+        let uf = UnionFind::new(junctions.len());
+
+        Ok(Self {
+            junctions_to_idx,
+            pairs,
+            uf,
+        })
+    }
+}
+
+impl Day08Setup {
+    pub fn largest_circuits(&self, nr_junctions: usize) -> usize {
+        let mut uf = self.uf.clone();
+
+        for (from, to, _) in self.pairs.iter().take(nr_junctions) {
+            uf.union(self.junctions_to_idx[from], self.junctions_to_idx[to]);
+        }
+        uf.sets()
+            .iter()
+            .map(|set| set.len())
+            .sorted()
+            .rev()
+            .take(3)
+            .product()
+    }
+
+    /// Product of the X coordinates of the pair that joins everything into one circuit,
+    /// which always exists as parsing guarantees at least 2 distinct junction boxes
+    pub fn last_connection(&self) -> u64 {
+        let mut uf = self.uf.clone();
+
+        for (from, to, _) in &self.pairs {
+            uf.union(self.junctions_to_idx[from], self.junctions_to_idx[to]);
+
+            if uf.set_count() == 1 {
+                return u64::from(from.x) * u64::from(to.x);
+            }
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_08_part_01_sample() {
+        let sample = vec![
+            "162,817,812",
+            "57,618,57",
+            "906,360,560",
+            "592,479,940",
+            "352,342,300",
+            "466,668,158",
+            "542,29,236",
+            "431,825,988",
+            "739,650,466",
+            "52,470,668",
+            "216,146,977",
+            "819,987,18",
+            "117,168,530",
+            "805,96,715",
+            "346,949,466",
+            "970,615,88",
+            "941,993,340",
+            "862,61,35",
+            "984,92,344",
+            "425,690,689",
+        ];
+
+        assert_eq!(Ok(40), solve_1(&sample, 10));
+    }
+
+    #[test]
+    fn day_08_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 8, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input, 1_000));
+    }
+
+    #[test]
+    fn day_08_part_02_sample() {
+        let sample = vec![
+            "162,817,812",
+            "57,618,57",
+            "906,360,560",
+            "592,479,940",
+            "352,342,300",
+            "466,668,158",
+            "542,29,236",
+            "431,825,988",
+            "739,650,466",
+            "52,470,668",
+            "216,146,977",
+            "819,987,18",
+            "117,168,530",
+            "805,96,715",
+            "346,949,466",
+            "970,615,88",
+            "941,993,340",
+            "862,61,35",
+            "984,92,344",
+            "425,690,689",
+        ];
+
+        assert_eq!(Ok(25_272), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_08_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 8, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_08_malformed_input() {
+        let expected = "a position like `162,817,812`";
+
+        assert_eq!(
+            Err(ParseError::at(2, 1, "57,618", expected)),
+            solve_2(&["162,817,812", "57,618"])
+        );
+        assert_eq!(
+            Err(ParseError::at(1, 5, "-817", "a coordinate")),
+            solve_1(&["162,-817,812"], 10)
+        );
+        assert_eq!(
+            Err(ParseError::at(2, 1, "", "a second junction box position")),
+            solve_2(&["1,1,1"])
+        );
+        assert_eq!(
+            Err(ParseError::at(
+                3,
+                1,
+                "1,1,1",
+                "a junction box position not seen before"
+            )),
+            solve_2(&["1,1,1", "2,2,2", "1,1,1"])
+        );
+    }
+
+    #[test]
+    fn day_08_large_coordinates() {
+        let junctions = ["100000,1,1", "100000,2,2", "4294967295,0,0"];
+
+        assert_eq!(Ok(2), solve_1(&junctions, 1));
+        assert_eq!(Ok(429_496_729_500_000), solve_2(&junctions));
+    }
+
+    #[test]
+    fn day_08_reference() {
+        reference::differential::<Day08>(2025, 8, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
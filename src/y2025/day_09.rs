@@ -0,0 +1,391 @@
+use crate::parse::{ParseError, parse_lines, parse_number};
+use crate::solutions::{Reference, Solution};
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+use std::str::FromStr;
+
+pub fn solve_1(red_tiles: &[&str]) -> Result<u64, ParseError> {
+    Day09::part_1(&Floor::try_from(red_tiles)?)
+}
+
+pub fn solve_2(red_tiles: &[&str]) -> Result<u64, ParseError> {
+    Day09::part_2(&Floor::try_from(red_tiles)?)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Floor;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Floor::try_from(input.lines().collect_vec().as_slice())
+    }
+
+    fn part_1(floor: &Self::Input<'_>) -> Result<u64, ParseError> {
+        Ok(floor
+            .red_tiles
+            .iter()
+            .copied()
+            .combinations(2)
+            .map(|rectangle| Rectangle::new(rectangle[0], rectangle[1]))
+            .map(|rectangle| rectangle.area())
+            .max()
+            .unwrap_or_default())
+    }
+
+    fn part_2(floor: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let compressed = CompressedFloor::new(floor);
+
+        Ok(floor
+            .red_tiles
+            .iter()
+            .copied()
+            .tuple_combinations()
+            .filter(|&(tile_a, tile_b)| compressed.is_within(tile_a, tile_b))
+            .map(|(tile_a, tile_b)| Rectangle::new(tile_a, tile_b).area())
+            .max()
+            .unwrap_or_default())
+    }
+}
+
+impl Reference for Day09 {
+    fn reference_1(floor: &Self::Input<'_>) -> Result<u64, ParseError> {
+        Ok(floor
+            .red_tiles
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
+            .max()
+            .unwrap_or_default())
+    }
+
+    /// Casts a ray from every tile in the row and column of a red tile, or right after one,
+    /// as every other tile is within the loop exactly when the nearest of those before it is
+    fn reference_2(floor: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let samples = |coordinates: Vec<u64>| {
+            coordinates
+                .into_iter()
+                .flat_map(|coordinate| [coordinate, coordinate + 1])
+                .sorted()
+                .dedup()
+                .collect_vec()
+        };
+        let xs = samples(floor.red_tiles.iter().map(|tile| tile.x).collect());
+        let ys = samples(floor.red_tiles.iter().map(|tile| tile.y).collect());
+        let red_tiles = floor.red_tiles.iter().copied().collect();
+        let within = ys
+            .iter()
+            .map(|&y| {
+                xs.iter()
+                    .map(|&x| RedTile { x, y }.is_within(&floor.edges, &red_tiles))
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        Ok(floor
+            .red_tiles
+            .iter()
+            .tuple_combinations()
+            .filter(|&(a, b)| {
+                let rows = ys
+                    .iter()
+                    .positions(|y| (a.y.min(b.y)..=a.y.max(b.y)).contains(y));
+                rows.flat_map(|row| {
+                    let columns = xs
+                        .iter()
+                        .positions(|x| (a.x.min(b.x)..=a.x.max(b.x)).contains(x));
+                    columns.map(move |column| (row, column))
+                })
+                .all(|(row, column)| within[row][column])
+            })
+            .map(|(a, b)| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
+            .max()
+            .unwrap_or_default())
+    }
+}
+
+/// Floor shrunk down to one cell per column and row holding a red tile, plus one per (non-empty) gap in between,
+/// surrounded by a border of cells that are always outside the loop
+struct CompressedFloor {
+    xs: Vec<u64>,
+    ys: Vec<u64>,
+    /// Number of cells outside the loop above and to the left of every cell, to count those within any rectangle at once
+    outside_before: Vec<Vec<usize>>,
+}
+
+impl CompressedFloor {
+    fn new(floor: &Floor) -> Self {
+        // First coordinate of every cell but the border ones, either a red tile's or the start of a gap
+        let compress = |coordinates: Vec<u64>| {
+            let coordinates = coordinates.into_iter().sorted().dedup().collect_vec();
+            let mut starts = vec![];
+            for (idx, &coordinate) in coordinates.iter().enumerate() {
+                starts.push(coordinate);
+                if coordinates
+                    .get(idx + 1)
+                    .is_some_and(|&next| next > coordinate + 1)
+                {
+                    starts.push(coordinate + 1);
+                }
+            }
+            starts
+        };
+        let mut compressed = Self {
+            xs: compress(floor.red_tiles.iter().map(|tile| tile.x).collect()),
+            ys: compress(floor.red_tiles.iter().map(|tile| tile.y).collect()),
+            outside_before: vec![],
+        };
+        let (width, height) = (compressed.xs.len() + 2, compressed.ys.len() + 2);
+
+        let mut on_loop = vec![vec![false; width]; height];
+        for (&from, &to) in floor.red_tiles.iter().circular_tuple_windows() {
+            let ((x_from, y_from), (x_to, y_to)) = (compressed.cell(from), compressed.cell(to));
+            for row in &mut on_loop[y_from.min(y_to)..=y_from.max(y_to)] {
+                row[x_from.min(x_to)..=x_from.max(x_to)].fill(true);
+            }
+        }
+
+        // Flood fill from a corner of the border, never crossing the loop
+        let mut outside = vec![vec![false; width]; height];
+        let mut to_visit: Vec<(usize, usize)> = vec![(0, 0)];
+        outside[0][0] = true;
+        while let Some((x, y)) = to_visit.pop() {
+            for (x, y) in [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ] {
+                if on_loop.get(y).and_then(|row| row.get(x)) == Some(&false) && !outside[y][x] {
+                    outside[y][x] = true;
+                    to_visit.push((x, y));
+                }
+            }
+        }
+
+        compressed.outside_before = vec![vec![0; width + 1]; height + 1];
+        for (y, row) in outside.iter().enumerate() {
+            for (x, &outside) in row.iter().enumerate() {
+                compressed.outside_before[y + 1][x + 1] = usize::from(outside)
+                    + compressed.outside_before[y][x + 1]
+                    + compressed.outside_before[y + 1][x]
+                    - compressed.outside_before[y][x];
+            }
+        }
+
+        compressed
+    }
+
+    /// Column and row of the cell holding `tile`
+    fn cell(&self, tile: RedTile) -> (usize, usize) {
+        let x = self.xs.binary_search(&tile.x).unwrap();
+        let y = self.ys.binary_search(&tile.y).unwrap();
+        (x + 1, y + 1)
+    }
+
+    /// Whether every tile of the rectangle with these opposite corners is red or green
+    fn is_within(&self, tile_a: RedTile, tile_b: RedTile) -> bool {
+        let ((x_a, y_a), (x_b, y_b)) = (self.cell(tile_a), self.cell(tile_b));
+        let (x_1, x_2) = (x_a.min(x_b), x_a.max(x_b) + 1);
+        let (y_1, y_2) = (y_a.min(y_b), y_a.max(y_b) + 1);
+        let before = &self.outside_before;
+
+        before[y_2][x_2] + before[y_1][x_1] == before[y_1][x_2] + before[y_2][x_1]
+    }
+}
+
+/// The loop of red tiles, along with the edges connecting each tile to the next
+#[derive(Debug, Clone)]
+pub struct Floor {
+    red_tiles: Vec<RedTile>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct RedTile {
+    x: u64,
+    y: u64,
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Edge {
+    Horizontal { x_1: u64, x_2: u64, y: u64 },
+    Vertical { x: u64, y_1: u64, y_2: u64 },
+}
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Rectangle {
+    top_left: RedTile,
+    bottom_right: RedTile,
+}
+
+impl TryFrom<&[&str]> for Floor {
+    type Error = ParseError;
+
+    fn try_from(lines: &[&str]) -> Result<Self, Self::Error> {
+        let red_tiles: Vec<RedTile> = parse_lines(lines)?;
+        let edges = red_tiles
+            .iter()
+            .circular_tuple_windows::<(_, _)>()
+            .enumerate()
+            .map(|(idx, (from, to))| {
+                if from.x == to.x {
+                    Ok(Edge::Vertical {
+                        x: from.x,
+                        y_1: from.y.min(to.y),
+                        y_2: from.y.max(to.y),
+                    })
+                } else if from.y == to.y {
+                    Ok(Edge::Horizontal {
+                        x_1: from.x.min(to.x),
+                        x_2: from.x.max(to.x),
+                        y: from.y,
+                    })
+                } else {
+                    let idx = (idx + 1) % lines.len();
+                    let expected = "a red tile in the same row or column as the previous one";
+                    Err(ParseError::at(idx + 1, 1, lines[idx], expected))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { red_tiles, edges })
+    }
+}
+
+impl FromStr for RedTile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::within(s, s, "a red tile like `7,1`"))?;
+
+        Ok(Self {
+            x: parse_number(s, x, "a coordinate")?,
+            y: parse_number(s, y, "a coordinate")?,
+        })
+    }
+}
+
+impl RedTile {
+    pub fn is_within(&self, edges: &[Edge], red_tiles: &FxHashSet<RedTile>) -> bool {
+        let on_red_tile = || red_tiles.contains(self);
+        let on_edge = || {
+            edges.iter().any(|edge| match edge {
+                Edge::Horizontal { x_1, x_2, y } => {
+                    &self.y == y && &self.x >= x_1 && &self.x <= x_2
+                }
+                Edge::Vertical { x, y_1, y_2 } => &self.x == x && &self.y >= y_1 && &self.y <= y_2,
+            })
+        };
+        let crossed_edges = || {
+            edges
+                .iter()
+                .filter(|edge| match edge {
+                    Edge::Horizontal { .. } => false,
+                    Edge::Vertical { x, y_1, y_2 } => {
+                        x > &self.x && y_1 <= &self.y && &self.y < y_2
+                    }
+                })
+                .count()
+        };
+
+        on_red_tile() || on_edge() || crossed_edges() % 2 == 1
+    }
+}
+
+impl Rectangle {
+    pub fn new(tile_a: RedTile, tile_b: RedTile) -> Self {
+        let top_left = RedTile {
+            x: tile_a.x.min(tile_b.x),
+            y: tile_a.y.min(tile_b.y),
+        };
+        let bottom_right = RedTile {
+            x: tile_a.x.max(tile_b.x),
+            y: tile_a.y.max(tile_b.y),
+        };
+        Self {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    pub fn area(&self) -> u64 {
+        let width = self.bottom_right.x - self.top_left.x + 1;
+        let height = self.bottom_right.y - self.top_left.y + 1;
+
+        width * height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_09_part_01_sample() {
+        let sample = vec!["7,1", "11,1", "11,7", "9,7", "9,5", "2,5", "2,3", "7,3"];
+
+        assert_eq!(Ok(50), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_09_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 9, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_09_part_02_sample() {
+        let sample = vec!["7,1", "11,1", "11,7", "9,7", "9,5", "2,5", "2,3", "7,3"];
+
+        assert_eq!(Ok(24), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_09_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 9, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_09_notch_within_rectangle() {
+        // The largest rectangle between these corners touches the loop all around, but holds a notch of it
+        let red_tiles = vec![
+            "7475,89478",
+            "24082,89478",
+            "24082,72237",
+            "77369,72237",
+            "77369,90151",
+            "88749,90151",
+            "88749,49881",
+            "77369,49881",
+            "77369,3344",
+            "24082,3344",
+            "24082,35114",
+            "7475,35114",
+        ];
+
+        assert_eq!(Ok(3_671_223_472), solve_2(&red_tiles));
+    }
+
+    #[test]
+    fn day_09_reference() {
+        reference::differential::<Day09>(2025, 9, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,416 @@
+use crate::parse::{ParseError, parse_lines, parse_number};
+use crate::solutions::{Reference, Solution};
+use aoc_util::a_star::Graphable;
+use aoc_util::bidirectional::bidirectional;
+use itertools::Itertools;
+use rayon::prelude::*;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use z3::ast::Int;
+use z3::{Optimize, SatResult};
+
+pub fn solve_1(machines: &[&str]) -> Result<u32, ParseError> {
+    Day10::part_1(&parse_lines(machines)?)
+}
+
+pub fn solve_2(machines: &[&str]) -> Result<u64, ParseError> {
+    Day10::part_2(&parse_lines(machines)?)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Machine>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(&input.lines().collect_vec())
+    }
+
+    fn part_1(machines: &Self::Input<'_>) -> Result<u32, ParseError> {
+        let presses = machines
+            .par_iter()
+            .map(|machine| machine.min_presses_lights())
+            .collect();
+        total_presses(machines, presses, "lights the buttons can turn on")
+    }
+
+    fn part_2(machines: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let presses = machines
+            .par_iter()
+            .map(|machine| machine.min_presses_joltages())
+            .collect();
+        total_presses(machines, presses, "joltages the buttons can reach")
+    }
+}
+
+impl Reference for Day10 {
+    fn reference_1(machines: &Self::Input<'_>) -> Result<u32, ParseError> {
+        let presses = machines
+            .iter()
+            .map(|machine| machine.min_presses_lights_exhaustive())
+            .collect();
+        total_presses(machines, presses, "lights the buttons can turn on")
+    }
+
+    fn reference_2(machines: &Self::Input<'_>) -> Result<u64, ParseError> {
+        let presses = machines
+            .iter()
+            .map(|machine| machine.min_presses_joltages_halving())
+            .collect();
+        total_presses(machines, presses, "joltages the buttons can reach")
+    }
+}
+
+/// Sums the fewest presses of every machine, reporting the first machine that cannot reach its target at all
+fn total_presses<T: Sum>(
+    machines: &[Machine],
+    presses: Vec<Option<T>>,
+    expected: &'static str,
+) -> Result<T, ParseError> {
+    presses
+        .into_iter()
+        .enumerate()
+        .map(|(idx, presses)| {
+            presses.ok_or_else(|| {
+                ParseError::unsolvable(idx + 1, 1, &machines[idx].to_string(), expected)
+            })
+        })
+        .sum()
+}
+
+/// Joltages reached by pressing a combination of buttons once each, and the number of buttons pressed
+type Combination = (Vec<u64>, u32);
+
+/// Lights of a machine, which are toggled by pressing buttons
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Lights(Vec<bool>);
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    lights: Vec<bool>,
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<u64>,
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(machine: &str) -> Result<Self, Self::Err> {
+        static RE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^\[(?<lights>[.#]+)] (?<buttons>(?:\((?:\d+,?)+\) ?)+) \{(?<joltages>(?:\d+,?)+)}$").unwrap()
+        });
+
+        let caps = RE.captures(machine).ok_or_else(|| {
+            let expected = "a machine like `[.##.] (3) (1,3) (2) {3,5,4}`";
+            ParseError::within(machine, machine, expected)
+        })?;
+        let lights = caps["lights"]
+            .chars()
+            .map(|light| match light {
+                '.' => false,
+                '#' => true,
+                _ => unreachable!(),
+            })
+            .collect_vec();
+        let buttons = caps
+            .name("buttons")
+            .unwrap()
+            .as_str()
+            .split_whitespace()
+            .map(|button| {
+                button[1..button.len() - 1]
+                    .split(',')
+                    .map(|idx| {
+                        let expected = "an index of one of the lights";
+                        match parse_number(machine, idx, expected)? {
+                            light if light < lights.len() => Ok(light),
+                            _ => Err(ParseError::within(machine, idx, expected)),
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let joltages = caps
+            .name("joltages")
+            .unwrap()
+            .as_str()
+            .split(',')
+            .map(|joltage| parse_number(machine, joltage, "a joltage"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            lights,
+            buttons,
+            joltages,
+        })
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lights = self
+            .lights
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect::<String>();
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|button| format!("({})", button.iter().join(",")))
+            .join(" ");
+        write!(
+            f,
+            "[{lights}] {buttons} {{{}}}",
+            self.joltages.iter().join(",")
+        )
+    }
+}
+
+impl Machine {
+    pub fn min_presses_lights(&self) -> Option<u32> {
+        // Pressing a button twice undoes it, so the same buttons lead back from the goal to the start
+        let start = Lights(vec![false; self.lights.len()]);
+        let goal = Lights(self.lights.clone());
+
+        // Never pressing a button twice, the fewest presses are far from overflowing
+        let (_, presses) = bidirectional(start, goal, &self.buttons).unwrap()?;
+        Some(presses)
+    }
+
+    pub fn min_presses_joltages(&self) -> Option<u64> {
+        // Collect which button indexes influence which joltage index
+        let buttons_per_joltage = {
+            let grouped = self
+                .buttons
+                .iter()
+                .enumerate()
+                .flat_map(|(button_idx, button)| {
+                    button
+                        .iter()
+                        .map(move |&joltage_idx| (joltage_idx, button_idx))
+                })
+                .into_group_map();
+
+            (0..self.joltages.len())
+                .map(|joltage_idx| grouped.get(&joltage_idx).cloned().unwrap_or_default())
+                .collect_vec()
+        };
+
+        // Setup variables
+        let opt = Optimize::new();
+        let total_presses = Int::fresh_const("total_presses");
+        let all_presses = (0..self.buttons.len())
+            .map(|idx| Int::fresh_const(&format!("button_{idx}")))
+            .collect_vec();
+
+        // Define constraints
+        all_presses
+            .iter()
+            .for_each(|presses| opt.assert(&presses.ge(0)));
+        (0..self.joltages.len()).for_each(|joltage_idx| {
+            let joltage = self.joltages[joltage_idx];
+            let buttons = buttons_per_joltage[joltage_idx]
+                .iter()
+                .map(|&button_idx| all_presses[button_idx].clone())
+                .collect_vec();
+            opt.assert(&Int::add(&buttons).eq(Int::from_u64(joltage)));
+        });
+        opt.assert(&total_presses.eq(Int::add(&all_presses)));
+
+        // Solve and return the result
+        opt.minimize(&total_presses);
+        match opt.check(&[]) {
+            SatResult::Sat => opt.get_model()?.eval(&total_presses, true)?.as_u64(),
+            SatResult::Unsat | SatResult::Unknown => None,
+        }
+    }
+
+    /// Every combination of buttons pressed once each, grouped by which of the first `len` lights they toggle
+    fn combinations(&self, len: usize) -> FxHashMap<Vec<bool>, Vec<Combination>> {
+        (0..1usize << self.buttons.len())
+            .map(|pressed| {
+                let mut joltages = vec![0; len];
+                for (idx, button) in self.buttons.iter().enumerate() {
+                    if pressed >> idx & 1 == 1 {
+                        for &light in button {
+                            if let Some(joltage) = joltages.get_mut(light) {
+                                *joltage += 1;
+                            }
+                        }
+                    }
+                }
+                (joltages, pressed.count_ones())
+            })
+            .into_group_map_by(|(joltages, _)| parity(joltages))
+            .into_iter()
+            .collect()
+    }
+
+    /// Pressing a button twice changes nothing, so tries pressing every combination of buttons once
+    pub fn min_presses_lights_exhaustive(&self) -> Option<u32> {
+        self.combinations(self.lights.len())
+            .get(&self.lights)?
+            .iter()
+            .map(|&(_, presses)| presses)
+            .min()
+    }
+
+    /// Whatever the buttons pressed an odd number of times, pressing them once first leaves even joltages,
+    /// reached by pressing the buttons half as many times twice, and so on until all joltages are 0
+    pub fn min_presses_joltages_halving(&self) -> Option<u64> {
+        fn min_presses(
+            joltages: &[u64],
+            combinations: &FxHashMap<Vec<bool>, Vec<Combination>>,
+            cache: &mut FxHashMap<Vec<u64>, Option<u64>>,
+        ) -> Option<u64> {
+            if joltages.iter().all(|&joltage| joltage == 0) {
+                return Some(0);
+            }
+            if let Some(&presses) = cache.get(joltages) {
+                return presses;
+            }
+
+            let presses = combinations
+                .get(&parity(joltages))
+                .into_iter()
+                .flatten()
+                .filter(|(pressed, _)| pressed.iter().zip(joltages).all(|(p, j)| p <= j))
+                .filter_map(|(pressed, presses)| {
+                    let halved = joltages
+                        .iter()
+                        .zip(pressed)
+                        .map(|(joltage, pressed)| (joltage - pressed) / 2)
+                        .collect_vec();
+                    min_presses(&halved, combinations, cache)
+                        .map(|half| u64::from(*presses) + 2 * half)
+                })
+                .min();
+
+            cache.insert(joltages.to_vec(), presses);
+            presses
+        }
+
+        let combinations = self.combinations(self.joltages.len());
+        min_presses(&self.joltages, &combinations, &mut FxHashMap::default())
+    }
+}
+
+fn parity(joltages: &[u64]) -> Vec<bool> {
+    joltages.iter().map(|joltage| joltage % 2 == 1).collect()
+}
+
+impl Graphable for Lights {
+    type Context = Vec<Vec<usize>>;
+    type Cost = u32;
+
+    fn neighbors(&self, buttons: &Vec<Vec<usize>>) -> Vec<(Self, u32)> {
+        buttons
+            .iter()
+            .map(|button| {
+                let mut lights = self.clone();
+                for &idx in button {
+                    lights.0[idx] = !lights.0[idx];
+                }
+                (lights, 1)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_10_part_01_sample() {
+        let sample = vec![
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}",
+            "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+            "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+        ];
+
+        assert_eq!(Ok(7), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_10_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 10, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_10_part_02_sample() {
+        let sample = vec![
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}",
+            "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+            "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+        ];
+
+        assert_eq!(Ok(33), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_10_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 10, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_10_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(1, 15, "7", "an index of one of the lights")),
+            solve_1(&["[.##.] (3) (1,7) {3,5,4,7}"])
+        );
+        assert_eq!(
+            Err(ParseError::at(
+                2,
+                1,
+                "[.##.] (3) {3,5,4,7",
+                "a machine like `[.##.] (3) (1,3) (2) {3,5,4}`"
+            )),
+            solve_2(&["[.##.] (3) (1,3) {3,5,4,7}", "[.##.] (3) {3,5,4,7"])
+        );
+        assert_eq!(Ok(1), solve_1(&["[.##.] (3) (1,2)  {3,5,4,7}"]));
+        assert_eq!(
+            Err(ParseError::unsolvable(
+                2,
+                1,
+                "[##] (0) {1,1}",
+                "lights the buttons can turn on"
+            )),
+            solve_1(&["[.#] (1) {0,1}", "[##] (0) {1,1}"])
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable(
+                1,
+                1,
+                "[##] (0) {1,1}",
+                "joltages the buttons can reach"
+            )),
+            Day10::reference_2(&Day10::parse("[##] (0) {1,1}\n").unwrap())
+        );
+    }
+
+    #[test]
+    fn day_10_reference() {
+        reference::differential::<Day10>(2025, 10, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,350 @@
+use crate::parse::ParseError;
+use crate::solutions::{Reference, Solution};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+pub fn solve_1(wiring: &[&str]) -> Result<u64, ParseError> {
+    Day11::part_1(&Wiring::try_from(wiring)?)
+}
+
+pub fn solve_2(wiring: &[&str]) -> Result<u64, ParseError> {
+    Day11::part_2(&Wiring::try_from(wiring)?)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Wiring<'a>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Wiring::try_from(input.lines().collect_vec().as_slice())
+    }
+
+    fn part_1(wiring: &Self::Input<'_>) -> Result<u64, ParseError> {
+        if !wiring.wires.contains_key("you") {
+            return Err(ParseError::unsolvable_input("a device `you`"));
+        }
+        Ok(wiring.nr_paths("you", "out"))
+    }
+
+    fn part_2(wiring: &Self::Input<'_>) -> Result<u64, ParseError> {
+        if !wiring.wires.contains_key("svr") {
+            return Err(ParseError::unsolvable_input("a device `svr`"));
+        }
+        Ok(wiring.nr_paths_specific("svr", "out"))
+    }
+}
+
+impl Reference for Day11 {
+    fn reference_1(wiring: &Self::Input<'_>) -> Result<u64, ParseError> {
+        if !wiring.wires.contains_key("you") {
+            return Err(ParseError::unsolvable_input("a device `you`"));
+        }
+        Ok(wiring.nr_paths_forward("you", "out"))
+    }
+
+    /// Paths through both `dac` and `fft` pass them in either order, never both as the wiring has no loops
+    fn reference_2(wiring: &Self::Input<'_>) -> Result<u64, ParseError> {
+        if !wiring.wires.contains_key("svr") {
+            return Err(ParseError::unsolvable_input("a device `svr`"));
+        }
+        let through = |first, second| {
+            wiring.nr_paths_forward("svr", first)
+                * wiring.nr_paths_forward(first, second)
+                * wiring.nr_paths_forward(second, "out")
+        };
+        Ok(through("dac", "fft") + through("fft", "dac"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Wiring<'a> {
+    wires: FxHashMap<&'a str, Vec<&'a str>>,
+}
+
+impl<'a> TryFrom<&[&'a str]> for Wiring<'a> {
+    type Error = ParseError;
+
+    fn try_from(wiring: &[&'a str]) -> Result<Self, Self::Error> {
+        let devices = wiring
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| {
+                let (from, to) = line.split_once(": ").ok_or_else(|| {
+                    ParseError::at(idx + 1, 1, line, "a device like `aaa: you hhh`")
+                })?;
+                let to = to.split_whitespace().collect_vec();
+                Ok((from, to))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let wires: FxHashMap<_, _> = devices.iter().cloned().collect();
+
+        for (idx, (_, outputs)) in devices.iter().enumerate() {
+            if let Some(output) = outputs
+                .iter()
+                .find(|&output| *output != "out" && !wires.contains_key(output))
+            {
+                return Err(ParseError::within(
+                    wiring[idx],
+                    output,
+                    "a device with its own line, or `out`",
+                )
+                .shift_lines(idx));
+            }
+        }
+
+        // Kahn's algorithm, the devices left with unvisited inputs lie on or after a loop
+        let mut nr_inputs: FxHashMap<&str, usize> =
+            wires.keys().map(|&device| (device, 0)).collect();
+        for output in wires.values().flatten() {
+            if let Some(count) = nr_inputs.get_mut(output) {
+                *count += 1;
+            }
+        }
+        let mut ready = nr_inputs
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&device, _)| device)
+            .collect_vec();
+        while let Some(device) = ready.pop() {
+            for &output in &wires[device] {
+                if let Some(count) = nr_inputs.get_mut(output) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push(output);
+                    }
+                }
+            }
+        }
+        if let Some(idx) = devices.iter().position(|(device, _)| nr_inputs[device] > 0) {
+            return Err(ParseError::at(
+                idx + 1,
+                1,
+                wiring[idx],
+                "wiring without loops",
+            ));
+        }
+
+        Ok(Self { wires })
+    }
+}
+
+impl<'a> Wiring<'a> {
+    /// Counts the paths to `to` from every device reachable from `from`, each device after the ones it outputs to
+    pub fn nr_paths(&self, from: &'a str, to: &str) -> u64 {
+        let mut nr_paths: FxHashMap<&str, u64> = FxHashMap::default();
+        for device in self.outputs_first(from) {
+            let count = match device == to {
+                true => 1,
+                false => self.outputs(device).map(|output| nr_paths[output]).sum(),
+            };
+            nr_paths.insert(device, count);
+        }
+        nr_paths[from]
+    }
+
+    /// Like [`Self::nr_paths`], but split up by which of `dac` (bit 0) and `fft` (bit 1) the paths pass through
+    pub fn nr_paths_specific(&self, from: &'a str, to: &str) -> u64 {
+        let mut nr_paths: FxHashMap<&str, [u64; 4]> = FxHashMap::default();
+        for device in self.outputs_first(from) {
+            let mut counts = [0; 4];
+            if device == to {
+                counts[0] = 1;
+            } else {
+                let passed = usize::from(device == "dac") | usize::from(device == "fft") << 1;
+                for output in self.outputs(device) {
+                    for (through, count) in nr_paths[output].iter().enumerate() {
+                        counts[through | passed] += count;
+                    }
+                }
+            }
+            nr_paths.insert(device, counts);
+        }
+        nr_paths[from][3]
+    }
+
+    /// Devices reachable from `from` (included), each one after all devices it outputs to,
+    /// found depth-first with an explicit stack as the wiring can be far too deep to recurse through
+    fn outputs_first(&self, from: &'a str) -> Vec<&'a str> {
+        let mut order = vec![];
+        let mut seen = FxHashSet::from_iter([from]);
+        let mut stack = vec![(from, 0)];
+
+        while let Some((device, idx)) = stack.pop() {
+            match self.outputs(device).nth(idx) {
+                Some(output) => {
+                    stack.push((device, idx + 1));
+                    if seen.insert(output) {
+                        stack.push((output, 0));
+                    }
+                }
+                None => order.push(device),
+            }
+        }
+
+        order
+    }
+
+    fn outputs(&self, device: &str) -> impl Iterator<Item = &'a str> {
+        self.wires.get(device).into_iter().flatten().copied()
+    }
+
+    /// Counts the paths to every device at once, passing them on from each device to its outputs in topological order
+    fn nr_paths_forward(&self, from: &'a str, to: &str) -> u64 {
+        let mut nr_inputs: FxHashMap<&str, usize> = FxHashMap::default();
+        for output in self.wires.values().flatten() {
+            *nr_inputs.entry(output).or_default() += 1;
+        }
+        let mut ready = self
+            .wires
+            .keys()
+            .filter(|device| !nr_inputs.contains_key(*device))
+            .copied()
+            .collect_vec();
+
+        let mut nr_paths: FxHashMap<&str, u64> = FxHashMap::from_iter([(from, 1)]);
+        while let Some(device) = ready.pop() {
+            let paths = nr_paths.get(device).copied().unwrap_or_default();
+            for &output in self.wires.get(device).into_iter().flatten() {
+                *nr_paths.entry(output).or_default() += paths;
+
+                let count = nr_inputs.get_mut(output).unwrap();
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(output);
+                }
+            }
+        }
+
+        nr_paths.get(to).copied().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::{answers, reference};
+
+    #[test]
+    fn day_11_part_01_sample() {
+        let sample = vec![
+            "aaa: you hhh",
+            "you: bbb ccc",
+            "bbb: ddd eee",
+            "ccc: ddd eee fff",
+            "ddd: ggg",
+            "eee: out",
+            "fff: out",
+            "ggg: out",
+            "hhh: ccc fff iii",
+            "iii: out",
+        ];
+
+        assert_eq!(Ok(5), solve_1(&sample));
+    }
+
+    #[test]
+    fn day_11_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 11, 1) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_1(&input));
+    }
+
+    #[test]
+    fn day_11_part_02_sample() {
+        let sample = vec![
+            "svr: aaa bbb",
+            "aaa: fft",
+            "fft: ccc",
+            "bbb: tty",
+            "tty: ccc",
+            "ccc: ddd eee",
+            "ddd: hub",
+            "hub: fff",
+            "eee: dac",
+            "dac: fff",
+            "fff: ggg hhh",
+            "ggg: out",
+            "hhh: out",
+        ];
+
+        assert_eq!(Ok(2), solve_2(&sample));
+    }
+
+    #[test]
+    fn day_11_part_02_solution() {
+        let Some((input, answer)) = answers::expected(2025, 11, 2) else {
+            return;
+        };
+        let input = input.lines().collect_vec();
+
+        assert_eq!(Ok(answer), solve_2(&input));
+    }
+
+    #[test]
+    fn day_11_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(
+                1,
+                1,
+                "you out",
+                "a device like `aaa: you hhh`"
+            )),
+            solve_1(&["you out"])
+        );
+        assert_eq!(
+            Err(ParseError::at(
+                2,
+                10,
+                "ccc",
+                "a device with its own line, or `out`"
+            )),
+            solve_1(&["you: aaa", "aaa: out ccc"])
+        );
+        assert_eq!(
+            Err(ParseError::at(2, 1, "aaa: bbb", "wiring without loops")),
+            solve_1(&["you: aaa", "aaa: bbb", "bbb: out aaa"])
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable_input("a device `you`")),
+            solve_1(&["a: out"])
+        );
+        assert_eq!(
+            Err(ParseError::unsolvable_input("a device `svr`")),
+            solve_2(&["you: out"])
+        );
+    }
+
+    #[test]
+    fn day_11_deep_wiring() {
+        let chain = (0..200_000)
+            .map(|idx| format!("a{idx}: a{}", idx + 1))
+            .collect_vec();
+        let wiring = [
+            "svr: you",
+            "you: fft a0",
+            "fft: a0",
+            "a200000: dac",
+            "dac: out",
+        ]
+        .into_iter()
+        .chain(chain.iter().map(String::as_str))
+        .collect_vec();
+
+        assert_eq!(Ok(2), solve_1(&wiring));
+        assert_eq!(Ok(1), solve_2(&wiring));
+    }
+
+    #[test]
+    fn day_11_reference() {
+        reference::differential::<Day11>(2025, 11, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,490 @@
+use crate::parse::{ParseError, parse_number, parse_within};
+use crate::solutions::{Reference, Solution};
+use itertools::Itertools;
+use rayon::prelude::*;
+use regex::Regex;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+pub fn solve_1(summary: &str, shortcuts: bool) -> Result<usize, ParseError> {
+    Ok(summary.parse::<Summary>()?.fitting_regions(shortcuts))
+}
+
+pub fn solve_2() {
+    // Decorate the North Pole
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Summary;
+    type Answer1 = usize;
+    type Answer2 = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.trim().parse()
+    }
+
+    fn part_1(summary: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(summary.fitting_regions(true))
+    }
+
+    fn part_2(_: &Self::Input<'_>) -> Result<(), ParseError> {
+        solve_2();
+        Ok(())
+    }
+}
+
+impl Reference for Day12 {
+    fn reference_1(summary: &Self::Input<'_>) -> Result<usize, ParseError> {
+        Ok(summary.fitting_regions(false))
+    }
+
+    fn reference_2(_: &Self::Input<'_>) -> Result<(), ParseError> {
+        solve_2();
+        Ok(())
+    }
+}
+
+const DIM: usize = 3;
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Shape {
+    grid: [[bool; DIM]; DIM],
+    coordinates: Vec<Coordinate>,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+struct Coordinate {
+    row: usize,
+    col: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Summary {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    width: usize,
+    height: usize,
+    shape_counts: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Placement {
+    Place,
+    Remove,
+}
+
+impl FromStr for Summary {
+    type Err = ParseError;
+
+    fn from_str(summary: &str) -> Result<Self, Self::Err> {
+        let (shapes, regions) = summary.rsplit_once("\n\n").ok_or_else(|| {
+            let expected = "shapes followed by regions, separated by a blank line";
+            ParseError::within(summary, summary, expected)
+        })?;
+        let shapes = shapes
+            .split("\n\n")
+            .map(|shape| parse_within::<Shape>(shape, summary))
+            .collect::<Result<Vec<_>, _>>()?;
+        let regions = regions
+            .split('\n')
+            .map(|line| {
+                let region = parse_within::<Region>(line, summary)?;
+                match region.shape_counts.len() <= shapes.len() {
+                    true => Ok(region),
+                    false => Err(ParseError::within(summary, line, "a count per known shape")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { shapes, regions })
+    }
+}
+
+impl Summary {
+    /// Number of regions all their presents fit in.
+    /// With `shortcuts` most regions are decided by [`Region::can_fit_heuristic`], or by not having enough room,
+    /// without it every region is backtracked over.
+    pub fn fitting_regions(&self, shortcuts: bool) -> usize {
+        self.regions
+            .par_iter()
+            .filter(|region| match shortcuts {
+                true => region.can_fit(&self.shapes),
+                false => region.can_fit_backtracking(&self.shapes),
+            })
+            .count()
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(shape: &str) -> Result<Self, Self::Err> {
+        let rows = shape.lines().skip(1).collect_vec();
+        if rows.len() != DIM {
+            return Err(ParseError::within(shape, shape, "a shape of 3 rows"));
+        }
+
+        let mut grid = [[false; DIM]; DIM];
+        for (row, line) in rows.into_iter().enumerate() {
+            if line.chars().count() != DIM {
+                return Err(ParseError::within(shape, line, "a shape row of 3 cells"));
+            }
+            for (col, (idx, c)) in line.char_indices().enumerate() {
+                grid[row][col] = match c {
+                    '#' => true,
+                    '.' => false,
+                    _ => {
+                        let text = &line[idx..idx + c.len_utf8()];
+                        return Err(ParseError::within(shape, text, "`#` or `.`"));
+                    }
+                };
+            }
+        }
+
+        Ok(Self {
+            grid,
+            coordinates: Self::coordinates(&grid),
+        })
+    }
+}
+
+impl Shape {
+    pub fn transformations(&self) -> Vec<Self> {
+        let mut transformations = vec![];
+        let mut current = self.clone();
+
+        for _ in 0..4 {
+            transformations.push(current.clone());
+            current = current.rotate_90();
+        }
+
+        current = self.flip_horizontal();
+
+        for _ in 0..4 {
+            transformations.push(current.clone());
+            current = current.rotate_90();
+        }
+
+        transformations.sort();
+        transformations.dedup();
+        transformations
+    }
+
+    fn rotate_90(&self) -> Self {
+        let mut new_grid = [[false; DIM]; DIM];
+        for (row, line) in self.grid.iter().enumerate() {
+            for (col, &cell) in line.iter().enumerate() {
+                new_grid[col][2 - row] = cell;
+            }
+        }
+        Self {
+            grid: new_grid,
+            coordinates: Self::coordinates(&new_grid),
+        }
+    }
+
+    fn flip_horizontal(&self) -> Self {
+        let mut new_grid = [[false; DIM]; DIM];
+        for (row, line) in self.grid.iter().enumerate() {
+            for (col, &cell) in line.iter().enumerate() {
+                new_grid[row][2 - col] = cell;
+            }
+        }
+        Self {
+            grid: new_grid,
+            coordinates: Self::coordinates(&new_grid),
+        }
+    }
+
+    fn coordinates(grid: &[[bool; DIM]; DIM]) -> Vec<Coordinate> {
+        (0..DIM)
+            .flat_map(|row| (0..DIM).map(move |col| Coordinate { row, col }))
+            .filter(|coord| grid[coord.row][coord.col])
+            .collect()
+    }
+}
+
+impl FromStr for Region {
+    type Err = ParseError;
+
+    fn from_str(region: &str) -> Result<Self, Self::Err> {
+        static RE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^(?<width>\d+)x(?<height>\d+): (?<shape_counts>.*)$").unwrap()
+        });
+
+        let caps = RE.captures(region).ok_or_else(|| {
+            ParseError::within(region, region, "a region like `12x5: 1 0 1 0 2 2`")
+        })?;
+
+        let width = parse_number(region, caps.name("width").unwrap().as_str(), "a width")?;
+        let height = parse_number(region, caps.name("height").unwrap().as_str(), "a height")?;
+        let shape_counts = caps
+            .name("shape_counts")
+            .unwrap()
+            .as_str()
+            .split_whitespace()
+            .map(|s| parse_number(region, s, "a shape count"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            width,
+            height,
+            shape_counts,
+        })
+    }
+}
+
+impl Region {
+    /// Whether all presents fit, only backtracking when neither the heuristic nor the room available decides it
+    pub fn can_fit(&self, shapes: &[Shape]) -> bool {
+        if self.can_fit_heuristic() {
+            return true;
+        }
+
+        // Not even enough room for every cell of every shape
+        let cells_required = self
+            .shape_counts
+            .iter()
+            .zip(shapes)
+            .map(|(count, shape)| count * shape.coordinates.len())
+            .sum::<usize>();
+        if cells_required > self.width * self.height {
+            return false;
+        }
+
+        self.can_fit_backtracking(shapes)
+    }
+
+    /// Whether all presents fit, trying every placement of every present until they do
+    pub fn can_fit_backtracking(&self, shapes: &[Shape]) -> bool {
+        let mut grid = vec![vec![false; self.width]; self.height];
+        let shapes_to_place = self
+            .shape_counts
+            .iter()
+            .enumerate()
+            .flat_map(|(shape_idx, &count)| {
+                (0..count).map(move |_| shapes[shape_idx].transformations())
+            })
+            .collect_vec();
+
+        self.backtrack(&mut grid, &shapes_to_place, 0)
+    }
+
+    pub fn can_fit_heuristic(&self) -> bool {
+        let shapes_max = (self.width / DIM) * (self.height / DIM);
+        let shapes_required = self.shape_counts.iter().sum::<usize>();
+
+        shapes_max >= shapes_required
+    }
+
+    fn backtrack(
+        &self,
+        grid: &mut Vec<Vec<bool>>,
+        shapes: &[Vec<Shape>],
+        shape_idx: usize,
+    ) -> bool {
+        if shape_idx >= shapes.len() {
+            return true;
+        }
+
+        for shape in &shapes[shape_idx] {
+            for row_origin in 0..self.height {
+                for col_origin in 0..self.width {
+                    if self.can_place_shape(grid, &shape.coordinates, row_origin, col_origin) {
+                        self.place_shape(
+                            grid,
+                            &shape.coordinates,
+                            row_origin,
+                            col_origin,
+                            Placement::Place,
+                        );
+
+                        if self.backtrack(grid, shapes, shape_idx + 1) {
+                            return true;
+                        }
+
+                        self.place_shape(
+                            grid,
+                            &shape.coordinates,
+                            row_origin,
+                            col_origin,
+                            Placement::Remove,
+                        );
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    fn can_place_shape(
+        &self,
+        grid: &[Vec<bool>],
+        coords: &[Coordinate],
+        row_origin: usize,
+        col_origin: usize,
+    ) -> bool {
+        for Coordinate {
+            row: row_delta,
+            col: col_delta,
+        } in coords
+        {
+            let row = row_origin + row_delta;
+            let col = col_origin + col_delta;
+
+            // Out of bounds
+            if row >= self.height || col >= self.width {
+                return false;
+            }
+
+            // Already occupied
+            if grid[row][col] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn place_shape(
+        &self,
+        grid: &mut [Vec<bool>],
+        coords: &[Coordinate],
+        row_origin: usize,
+        col_origin: usize,
+        placement: Placement,
+    ) {
+        for Coordinate {
+            row: row_delta,
+            col: col_delta,
+        } in coords
+        {
+            let row = row_origin + row_delta;
+            let col = col_origin + col_delta;
+
+            grid[row][col] = match placement {
+                Placement::Place => true,
+                Placement::Remove => false,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers, generate, reference};
+
+    #[ignore = "Backtracking algorithm is very slow (+-45s on a MBP M1 Max)"]
+    #[test]
+    fn day_12_part_01_sample() {
+        let sample = "\
+            0:\n\
+            ###\n\
+            ##.\n\
+            ##.\n\
+            \n\
+            1:\n\
+            ###\n\
+            ##.\n\
+            .##\n\
+            \n\
+            2:\n\
+            .##\n\
+            ###\n\
+            ##.\n\
+            \n\
+            3:\n\
+            ##.\n\
+            ###\n\
+            ##.\n\
+            \n\
+            4:\n\
+            ###\n\
+            #..\n\
+            ###\n\
+            \n\
+            5:\n\
+            ###\n\
+            .#.\n\
+            ###\n\
+            \n\
+            4x4: 0 0 0 0 2 0\n\
+            12x5: 1 0 1 0 2 2\n\
+            12x5: 1 0 1 0 3 2\
+        ";
+
+        assert_eq!(Ok(2), solve_1(sample, true));
+    }
+
+    #[test]
+    fn day_12_part_01_solution() {
+        let Some((input, answer)) = answers::expected(2025, 12, 1) else {
+            return;
+        };
+
+        assert_eq!(Ok(answer), solve_1(input.trim(), true));
+    }
+
+    #[test]
+    fn day_12_part_02_sample() {
+        solve_2();
+    }
+
+    #[test]
+    fn day_12_part_02_solution() {
+        solve_2();
+    }
+
+    #[test]
+    fn day_12_malformed_input() {
+        assert_eq!(
+            Err(ParseError::at(2, 3, "x", "`#` or `.`")),
+            solve_1("0:\n##x\n###\n###\n\n4x4: 1", true)
+        );
+        assert_eq!(
+            Err(ParseError::at(6, 1, "4x4: 1 2", "a count per known shape")),
+            solve_1("0:\n###\n###\n###\n\n4x4: 1 2", true)
+        );
+    }
+
+    #[test]
+    fn day_12_generated_regions_need_backtracking() {
+        let summary: Summary = generate::generate(2025, 12, 200, 0)
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        let undecided = summary
+            .regions
+            .iter()
+            .filter(|region| {
+                let cells = region
+                    .shape_counts
+                    .iter()
+                    .zip(&summary.shapes)
+                    .map(|(count, shape)| count * shape.coordinates.len())
+                    .sum::<usize>();
+                !region.can_fit_heuristic() && cells <= region.width * region.height
+            })
+            .map(|region| region.can_fit_backtracking(&summary.shapes))
+            .collect_vec();
+
+        // Regions only backtracking decides, some of which fit by interlocking while others do not
+        assert!(undecided.contains(&true));
+        assert!(undecided.contains(&false));
+    }
+
+    #[test]
+    fn day_12_reference() {
+        reference::differential::<Day12>(2025, 12, 100)
+            .unwrap_or_else(|mismatch| panic!("{mismatch}"));
+    }
+}
@@ -0,0 +1,901 @@
+//! A* search over any state implementing [`Graphable`].
+
+use itertools::Itertools;
+use num_traits::{CheckedAdd, Zero};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::iter;
+
+/// Cost of a step or path: any totally ordered number whose sums are checked for overflow,
+/// like `u32`, `u64` or `usize`, or a wrapper around fractional costs implementing the same traits
+pub trait Cost: Copy + Ord + Zero + CheckedAdd {}
+
+impl<C: Copy + Ord + Zero + CheckedAdd> Cost for C {}
+
+/// State in a graph that can be searched with [`a_star`]
+pub trait Graphable: Clone + Eq + Hash {
+    /// Data shared by all states, like the grid they live on, handed to every call
+    type Context;
+
+    /// Type of the cost of each step, and of the total cost of a path
+    type Cost: Cost;
+
+    /// All states reachable in a single step, each with the (non-negative) cost of that step
+    fn neighbors(&self, context: &Self::Context) -> Vec<(Self, Self::Cost)>;
+
+    /// Estimate of the remaining cost to reach `target`.
+    /// Never overestimating it (like a Manhattan distance on a grid) makes it admissible,
+    /// never dropping by more than the cost of a step on top of that makes it consistent:
+    /// see [`Consistency`] for which one guarantees what.
+    /// Returning `0` (the default) turns the search into Dijkstra's algorithm.
+    /// Searches without a target, like [`dijkstra`](crate::search::dijkstra), never call it.
+    fn heuristic(&self, _target: &Self, _context: &Self::Context) -> Self::Cost {
+        Self::Cost::zero()
+    }
+
+    /// Whether this state ends the search, by default only when it equals `target`
+    fn is_target(&self, target: &Self) -> bool {
+        self == target
+    }
+
+    /// All states from which this one is reachable in a single step, each with the cost of that step,
+    /// as walked by searches going backwards from the target like [`bidirectional`](crate::bidirectional::bidirectional).
+    /// By default the [`neighbors`](Graphable::neighbors), for graphs where every step can be taken back at the same cost.
+    fn predecessors(&self, context: &Self::Context) -> Vec<(Self, Self::Cost)> {
+        self.neighbors(context)
+    }
+}
+
+/// Hook into a search, called as it goes to count or log its work.
+/// `()` ignores everything, while [`Counts`] counts it.
+pub trait Observer<T> {
+    /// Called for every state whose neighbors the search is about to look at
+    fn expanded(&mut self, _state: &T) {}
+
+    /// Called for every state the search queues to expand later
+    fn pushed(&mut self, _state: &T) {}
+}
+
+impl<T> Observer<T> for () {}
+
+/// [`Observer`] counting the states a search expanded and pushed
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Counts {
+    /// Number of states whose neighbors were looked at
+    pub expanded: usize,
+    /// Number of states queued to be expanded later, including those that never were
+    pub pushed: usize,
+}
+
+impl<T> Observer<T> for Counts {
+    fn expanded(&mut self, _: &T) {
+        self.expanded += 1;
+    }
+
+    fn pushed(&mut self, _: &T) {
+        self.pushed += 1;
+    }
+}
+
+/// Options of an [`a_star_with`] search
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct SearchConfig {
+    /// What the heuristic guarantees, and so whether states may have to be expanded more than once
+    pub consistency: Consistency,
+    /// Which state to expand first among those with the same estimated total cost
+    pub tie_breaking: TieBreaking,
+}
+
+/// What a [`heuristic`](Graphable::heuristic) guarantees
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Consistency {
+    /// Never dropping by more than the cost of a step: a state is reached at its lowest cost the first time
+    /// it is expanded, so it never needs to be expanded again.
+    /// The path found with a heuristic that is only admissible might not be a shortest one.
+    #[default]
+    Consistent,
+    /// Only never overestimating: a state can still be reached at a lower cost after it was expanded,
+    /// it then gets reopened and expanded again, so the path found is always a shortest one
+    Admissible,
+}
+
+/// Order in which states with the same estimated total cost are expanded
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum TieBreaking {
+    /// The states furthest from the start (with the highest cost so far) first,
+    /// which are likely the closest to the target, on an open grid heading straight for it
+    #[default]
+    HigherG,
+    /// Any of them, as the heap happens to order them
+    Arbitrary,
+}
+
+/// Work done by an [`a_star_with`] search
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    /// Number of times the neighbors of a state were looked at, reopened states counting again
+    pub expansions: usize,
+    /// Largest number of states waiting to be expanded at any time, including outdated ones
+    pub max_frontier: usize,
+}
+
+/// Every state along a path, both ends included, and the total cost of that path
+pub type Path<T> = (Vec<T>, <T as Graphable>::Cost);
+
+/// Error of a search reaching a state whose cost, or estimated total cost, no longer fits [`Graphable::Cost`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Overflow<T> {
+    /// State that could not be given a cost
+    pub state: T,
+}
+
+impl<T: Debug> Display for Overflow<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the cost of reaching {:?} overflows", self.state)
+    }
+}
+
+impl<T: Debug> Error for Overflow<T> {}
+
+/// Every shortest path of a search by [`a_star_all`], as all predecessors along a shortest path of each state
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ShortestPaths<T: Graphable> {
+    /// Total cost of each of the paths
+    pub cost: T::Cost,
+    /// Every state ending the search that was reached at that cost
+    pub ends: Vec<T>,
+    /// All states preceding a state along one of its shortest paths, none for the start
+    pub predecessors: FxHashMap<T, Vec<T>>,
+}
+
+impl<T: Graphable> ShortestPaths<T> {
+    /// Number of distinct shortest paths, over all ends, or `None` if there are more than a `u64` can hold
+    pub fn count(&self) -> Option<u64> {
+        let states = self.states();
+        // Number of predecessors each state still waits on before its own count is final
+        let mut waiting: FxHashMap<&T, usize> = FxHashMap::default();
+        let mut successors: FxHashMap<&T, Vec<&T>> = FxHashMap::default();
+        for state in &states {
+            let predecessors = self.predecessors.get(state).map_or(&[][..], Vec::as_slice);
+            waiting.insert(state, predecessors.len());
+            for predecessor in predecessors {
+                successors.entry(predecessor).or_default().push(state);
+            }
+        }
+
+        // Walked in topological order from the start, every path to a state goes through one of its predecessors
+        let mut ready = states
+            .iter()
+            .filter(|state| waiting[state] == 0)
+            .collect_vec();
+        let mut counts: FxHashMap<&T, u64> = ready.iter().map(|&state| (state, 1)).collect();
+        while let Some(state) = ready.pop() {
+            let count = counts[state];
+            for &successor in successors.get(state).into_iter().flatten() {
+                let total = counts.entry(successor).or_insert(0);
+                *total = total.checked_add(count)?;
+
+                let left = waiting.get_mut(successor).unwrap();
+                *left -= 1;
+                if *left == 0 {
+                    ready.push(successor);
+                }
+            }
+        }
+
+        self.ends
+            .iter()
+            .try_fold(0_u64, |total, end| total.checked_add(counts[end]))
+    }
+
+    /// Every state lying on at least one of the shortest paths
+    pub fn states(&self) -> FxHashSet<T> {
+        let mut states = FxHashSet::default();
+        let mut to_visit = self.ends.clone();
+
+        while let Some(state) = to_visit.pop() {
+            if states.contains(&state) {
+                continue;
+            }
+            if let Some(predecessors) = self.predecessors.get(&state) {
+                to_visit.extend(predecessors.iter().cloned());
+            }
+            states.insert(state);
+        }
+
+        states
+    }
+
+    /// Iterates over every shortest path, each as all states along it (both ends included)
+    pub fn paths(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        // Partial paths walked backwards from an end, complete once they reach the start
+        let mut to_extend = self.ends.iter().map(|end| vec![end.clone()]).collect_vec();
+
+        iter::from_fn(move || {
+            while let Some(mut path) = to_extend.pop() {
+                match self.predecessors.get(path.last().unwrap()) {
+                    Some(predecessors) => {
+                        for predecessor in predecessors {
+                            let mut longer = path.clone();
+                            longer.push(predecessor.clone());
+                            to_extend.push(longer);
+                        }
+                    }
+                    None => {
+                        path.reverse();
+                        return Some(path);
+                    }
+                }
+            }
+
+            None
+        })
+    }
+}
+
+/// Finds a cheapest path from `start` to a state that [`is_target`](Graphable::is_target),
+/// returning every state along it (both ends included) and its total cost, or `None` if none is reachable.
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+///
+/// ```
+/// use aoc_util::a_star::{Graphable, a_star};
+///
+/// /// Position on a number line, stepping 1 for a cost of 1 or jumping 5 for a cost of 2
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Position(i32);
+///
+/// impl Graphable for Position {
+///     type Context = ();
+///     type Cost = u32;
+///
+///     fn neighbors(&self, _: &()) -> Vec<(Self, u32)> {
+///         vec![(Position(self.0 + 1), 1), (Position(self.0 - 1), 1), (Position(self.0 + 5), 2)]
+///     }
+///
+///     fn heuristic(&self, target: &Self, _: &()) -> u32 {
+///         target.0.abs_diff(self.0).div_ceil(5)
+///     }
+/// }
+///
+/// let (path, cost) = a_star(Position(0), Position(9), &()).unwrap().unwrap();
+///
+/// assert_eq!(5, cost);
+/// assert_eq!(Position(9), path[path.len() - 1]);
+/// ```
+pub fn a_star<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    a_star_observed(start, target, context, &mut ())
+}
+
+/// [`a_star`], reporting every state it expands and pushes to `observer`
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+pub fn a_star_observed<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+    observer: &mut impl Observer<T>,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    a_star_with(start, target, context, SearchConfig::default(), observer).map(|(path, _)| path)
+}
+
+/// [`a_star`] configured by `config`, reporting every state it expands and pushes to `observer`
+/// and returning how much work that took along with the path
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+pub fn a_star_with<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+    config: SearchConfig,
+    observer: &mut impl Observer<T>,
+) -> Result<(Option<Path<T>>, SearchStats), Overflow<T>> {
+    let mut stats = SearchStats::default();
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: FxHashMap<T, T> = FxHashMap::default();
+    let mut g_scores: FxHashMap<T, T::Cost> = FxHashMap::default();
+    let mut visited: FxHashSet<T> = FxHashSet::default();
+
+    let h_score = start.heuristic(&target, context);
+    let g_score = T::Cost::zero();
+    let f_score = checked_add(&start, g_score, h_score)?;
+
+    g_scores.insert(start.clone(), g_score);
+    observer.pushed(&start);
+    open_set.push(Node::new(
+        start.clone(),
+        g_score,
+        f_score,
+        config.tie_breaking,
+    ));
+    stats.max_frontier = 1;
+
+    while let Some(Node {
+        state: current,
+        g_score,
+        ..
+    }) = open_set.pop()
+    {
+        // Outdated, the state was pushed again since at a lower cost
+        if g_score > g_scores[&current] {
+            continue;
+        }
+        if config.consistency == Consistency::Consistent {
+            if visited.contains(&current) {
+                continue;
+            }
+            visited.insert(current.clone());
+        }
+
+        if current.is_target(&target) {
+            let path = reconstruct_path(&came_from, current);
+            return Ok((Some((path, g_score)), stats));
+        }
+        observer.expanded(&current);
+        stats.expansions += 1;
+
+        for (neighbor, cost) in current.neighbors(context) {
+            // Consistent: an expanded state already has its lowest cost, so it is closed for good.
+            // Admissible: nothing is ever closed, a state expanded too early is reopened by the cost check below.
+            if visited.contains(&neighbor) {
+                continue;
+            }
+
+            let potentially_better_g = checked_add(&neighbor, g_score, cost)?;
+
+            if let Some(&existing_g) = g_scores.get(&neighbor)
+                && potentially_better_g >= existing_g
+            {
+                continue;
+            }
+
+            let h_score = neighbor.heuristic(&target, context);
+            let g_score = potentially_better_g;
+            let f_score = checked_add(&neighbor, g_score, h_score)?;
+
+            came_from.insert(neighbor.clone(), current.clone());
+            g_scores.insert(neighbor.clone(), g_score);
+            observer.pushed(&neighbor);
+            open_set.push(Node::new(neighbor, g_score, f_score, config.tie_breaking));
+            stats.max_frontier = stats.max_frontier.max(open_set.len());
+        }
+    }
+
+    Ok((None, stats))
+}
+
+/// Finds every cheapest path from `start` to the states that [`is_target`](Graphable::is_target),
+/// recording all predecessors a state can be reached from at its lowest cost instead of a single one.
+/// Steps must cost more than `0`, otherwise there can be infinitely many shortest paths.
+///
+/// ```
+/// use aoc_util::a_star::{Graphable, a_star_all};
+///
+/// /// Position on a number line, stepping 1 for a cost of 1 or 2 for a cost of 2
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Position(u32);
+///
+/// impl Graphable for Position {
+///     type Context = ();
+///     type Cost = u32;
+///
+///     fn neighbors(&self, _: &()) -> Vec<(Self, u32)> {
+///         vec![(Position(self.0 + 1), 1), (Position(self.0 + 2), 2)]
+///     }
+///
+///     fn heuristic(&self, target: &Self, _: &()) -> u32 {
+///         target.0.abs_diff(self.0)
+///     }
+/// }
+///
+/// let paths = a_star_all(Position(0), Position(4), &()).unwrap().unwrap();
+///
+/// assert_eq!(4, paths.cost);
+/// assert_eq!(Some(5), paths.count());
+/// assert_eq!(5, paths.states().len());
+/// assert!(paths.paths().any(|path| path == [Position(0), Position(2), Position(4)]));
+/// ```
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+pub fn a_star_all<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+) -> Result<Option<ShortestPaths<T>>, Overflow<T>> {
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: FxHashMap<T, Vec<T>> = FxHashMap::default();
+    let mut g_scores: FxHashMap<T, T::Cost> = FxHashMap::default();
+    let mut visited: FxHashSet<T> = FxHashSet::default();
+    let mut shortest: Option<(T::Cost, Vec<T>)> = None;
+
+    let h_score = start.heuristic(&target, context);
+    let g_score = T::Cost::zero();
+    let f_score = checked_add(&start, g_score, h_score)?;
+
+    g_scores.insert(start.clone(), g_score);
+    open_set.push(Node::new(
+        start.clone(),
+        g_score,
+        f_score,
+        TieBreaking::HigherG,
+    ));
+
+    while let Some(Node {
+        state: current,
+        g_score,
+        f_score,
+        ..
+    }) = open_set.pop()
+    {
+        // Every state left is only on more expensive paths
+        if let Some((cost, _)) = &shortest
+            && f_score > *cost
+        {
+            break;
+        }
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.insert(current.clone());
+
+        if current.is_target(&target) {
+            shortest.get_or_insert((g_score, vec![])).1.push(current);
+            continue;
+        }
+
+        for (neighbor, cost) in current.neighbors(context) {
+            let potentially_better_g = checked_add(&neighbor, g_score, cost)?;
+
+            // An equally cheap predecessor can still show up after the state itself was visited
+            match g_scores.get(&neighbor) {
+                Some(&existing_g) if potentially_better_g > existing_g => continue,
+                Some(&existing_g) if potentially_better_g == existing_g => {
+                    came_from.entry(neighbor).or_default().push(current.clone());
+                    continue;
+                }
+                _ if visited.contains(&neighbor) => continue,
+                _ => {}
+            }
+
+            let h_score = neighbor.heuristic(&target, context);
+            let g_score = potentially_better_g;
+            let f_score = checked_add(&neighbor, g_score, h_score)?;
+
+            came_from.insert(neighbor.clone(), vec![current.clone()]);
+            g_scores.insert(neighbor.clone(), g_score);
+            open_set.push(Node::new(neighbor, g_score, f_score, TieBreaking::HigherG));
+        }
+    }
+
+    Ok(shortest.map(|(cost, ends)| ShortestPaths {
+        cost,
+        ends,
+        predecessors: came_from,
+    }))
+}
+
+/// Sum of two costs towards `state`, or an [`Overflow`] on it if that sum does not fit
+pub(crate) fn checked_add<T: Graphable>(
+    state: &T,
+    a: T::Cost,
+    b: T::Cost,
+) -> Result<T::Cost, Overflow<T>> {
+    a.checked_add(&b).ok_or_else(|| Overflow {
+        state: state.clone(),
+    })
+}
+
+#[derive(Clone)]
+pub(crate) struct Node<T, C> {
+    pub(crate) state: T,
+    pub(crate) g_score: C,
+    pub(crate) f_score: C,
+    /// Among nodes with the same `f_score`, the one with the highest `tie_break` comes first
+    pub(crate) tie_break: C,
+}
+
+impl<T, C: Cost> Node<T, C> {
+    pub(crate) fn new(state: T, g_score: C, f_score: C, tie_breaking: TieBreaking) -> Self {
+        let tie_break = match tie_breaking {
+            TieBreaking::HigherG => g_score,
+            TieBreaking::Arbitrary => C::zero(),
+        };
+
+        Self {
+            state,
+            g_score,
+            f_score,
+            tie_break,
+        }
+    }
+}
+
+impl<T, C: Ord> Ord for Node<T, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse ordering on the `f_score` to make the `BinaryHeap` a min-heap
+        other
+            .f_score
+            .cmp(&self.f_score)
+            .then_with(|| self.tie_break.cmp(&other.tie_break))
+    }
+}
+
+impl<T, C: Ord> PartialOrd for Node<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, C: Ord> PartialEq for Node<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, C: Ord> Eq for Node<T, C> {}
+
+pub(crate) fn reconstruct_path<T: Graphable>(
+    came_from: &FxHashMap<T, T>,
+    mut current: T,
+) -> Vec<T> {
+    let mut path = vec![current.clone()];
+
+    while let Some(prev) = came_from.get(&current) {
+        current = prev.clone();
+        path.push(current.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Open cells of a grid, walkable in the 4 directions at a cost of 1
+    struct Grid(Vec<&'static str>);
+
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Cell(usize, usize);
+
+    impl Graphable for Cell {
+        type Context = Grid;
+        type Cost = u32;
+
+        fn neighbors(&self, grid: &Grid) -> Vec<(Self, u32)> {
+            let Cell(x, y) = *self;
+            [
+                (x + 1, y),
+                (x.wrapping_sub(1), y),
+                (x, y + 1),
+                (x, y.wrapping_sub(1)),
+            ]
+            .into_iter()
+            .filter(|&(x, y)| grid.0.get(y).and_then(|row| row.as_bytes().get(x)) == Some(&b'.'))
+            .map(|(x, y)| (Cell(x, y), 1))
+            .collect()
+        }
+
+        fn heuristic(&self, target: &Self, _: &Grid) -> u32 {
+            (self.0.abs_diff(target.0) + self.1.abs_diff(target.1)) as u32
+        }
+    }
+
+    /// Any cell in the same column as the target ends the search
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Column(Cell);
+
+    impl Graphable for Column {
+        type Context = Grid;
+        type Cost = u32;
+
+        fn neighbors(&self, grid: &Grid) -> Vec<(Self, u32)> {
+            self.0
+                .neighbors(grid)
+                .into_iter()
+                .map(|(cell, cost)| (Column(cell), cost))
+                .collect()
+        }
+
+        fn heuristic(&self, target: &Self, _: &Grid) -> u32 {
+            self.0.0.abs_diff(target.0.0) as u32
+        }
+
+        fn is_target(&self, target: &Self) -> bool {
+            self.0.0 == target.0.0
+        }
+    }
+
+    /// Position on a number line, jumping ahead by ever larger powers of 2, at a cost of one more than the jump
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Jump<C> {
+        position: C,
+        power: u32,
+    }
+
+    impl<C: Cost + From<u8> + Debug + Hash> Graphable for Jump<C> {
+        type Context = ();
+        type Cost = C;
+
+        fn neighbors(&self, _: &()) -> Vec<(Self, C)> {
+            let jump = (0..self.power).fold(C::from(1), |jump, _| jump + jump);
+            let next = Jump {
+                position: self.position + jump,
+                power: self.power + 1,
+            };
+            vec![(next, jump + C::from(1))]
+        }
+
+        fn heuristic(&self, _: &Self, _: &()) -> C {
+            C::zero()
+        }
+
+        fn is_target(&self, target: &Self) -> bool {
+            self.position >= target.position
+        }
+    }
+
+    /// Graph on which heading for `A` right away looks cheapest, even though the detour through `B` is shorter.
+    /// The heuristic never overestimates, but drops by 6 on the step from `B` to `A` that only costs 1.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    enum Detour {
+        S,
+        A,
+        B,
+        G,
+    }
+
+    impl Graphable for Detour {
+        type Context = ();
+        type Cost = u32;
+
+        fn neighbors(&self, _: &()) -> Vec<(Self, u32)> {
+            match self {
+                Detour::S => vec![(Detour::A, 4), (Detour::B, 1)],
+                Detour::B => vec![(Detour::A, 1)],
+                Detour::A => vec![(Detour::G, 5)],
+                Detour::G => vec![],
+            }
+        }
+
+        fn heuristic(&self, _: &Self, _: &()) -> u32 {
+            match self {
+                Detour::B => 6,
+                _ => 0,
+            }
+        }
+    }
+
+    fn grid() -> Grid {
+        Grid(vec![".....", "####.", ".....", ".####", "....."])
+    }
+
+    #[test]
+    fn a_star_finds_shortest_path() {
+        let (path, cost) = a_star(Cell(0, 0), Cell(4, 4), &grid()).unwrap().unwrap();
+
+        assert_eq!(16, cost);
+        assert_eq!(17, path.len());
+        assert_eq!(Cell(0, 0), path[0]);
+        assert_eq!(Cell(4, 4), path[16]);
+        assert!(
+            path.windows(2)
+                .all(|step| step[0].heuristic(&step[1], &grid()) == 1)
+        );
+    }
+
+    #[test]
+    fn a_star_start_is_target() {
+        assert_eq!(
+            Ok(Some((vec![Cell(2, 2)], 0))),
+            a_star(Cell(2, 2), Cell(2, 2), &grid())
+        );
+    }
+
+    #[test]
+    fn a_star_unreachable() {
+        let walled = Grid(vec!["..#..", "..#.."]);
+
+        assert_eq!(Ok(None), a_star(Cell(0, 0), Cell(4, 0), &walled));
+    }
+
+    #[test]
+    fn a_star_custom_target() {
+        let (path, cost) = a_star(Column(Cell(0, 0)), Column(Cell(3, 4)), &grid())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(3, cost);
+        assert_eq!(Column(Cell(3, 0)), path[3]);
+    }
+
+    #[test]
+    fn a_star_wide_costs() {
+        let start = Jump::<u64> {
+            position: 0,
+            power: 0,
+        };
+        let target = Jump {
+            position: 1 << 40,
+            power: 0,
+        };
+        let (path, cost) = a_star(start, target, &()).unwrap().unwrap();
+
+        assert_eq!((1 << 41) - 1 + 41, cost);
+        assert_eq!(42, path.len());
+    }
+
+    #[test]
+    fn a_star_overflow() {
+        let start = Jump::<u8> {
+            position: 0,
+            power: 0,
+        };
+        let target = Jump {
+            position: u8::MAX,
+            power: 0,
+        };
+
+        assert_eq!(
+            Err(Overflow {
+                state: Jump {
+                    position: 255,
+                    power: 8
+                }
+            }),
+            a_star(start, target, &())
+        );
+    }
+
+    #[test]
+    fn a_star_all_counts_paths() {
+        let open = Grid(vec!["...", "...", "..."]);
+        let paths = a_star_all(Cell(0, 0), Cell(2, 2), &open).unwrap().unwrap();
+
+        assert_eq!(4, paths.cost);
+        assert_eq!(vec![Cell(2, 2)], paths.ends);
+        assert_eq!(Some(6), paths.count());
+        assert_eq!(9, paths.states().len());
+
+        let all = paths.paths().collect_vec();
+        assert_eq!(6, all.len());
+        assert_eq!(6, all.iter().unique().count());
+        assert!(
+            all.iter()
+                .all(|path| path.len() == 5 && path[0] == Cell(0, 0) && path[4] == Cell(2, 2))
+        );
+    }
+
+    #[test]
+    fn a_star_all_count_overflow() {
+        let open = |size: usize| Grid(vec![".".repeat(size).leak(); size]);
+
+        // 66 choose 33 paths still fit, but 68 choose 34 do not
+        let paths = a_star_all(Cell(0, 0), Cell(33, 33), &open(34))
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(7_219_428_434_016_265_740), paths.count());
+
+        let paths = a_star_all(Cell(0, 0), Cell(34, 34), &open(35))
+            .unwrap()
+            .unwrap();
+        assert_eq!(None, paths.count());
+    }
+
+    #[test]
+    fn a_star_all_count_long_path() {
+        let corridor = Grid(vec![".".repeat(200_000).leak()]);
+        let paths = a_star_all(Cell(0, 0), Cell(199_999, 0), &corridor)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Some(1), paths.count());
+    }
+
+    #[test]
+    fn a_star_all_single_path() {
+        let paths = a_star_all(Cell(0, 0), Cell(4, 4), &grid())
+            .unwrap()
+            .unwrap();
+        let (path, cost) = a_star(Cell(0, 0), Cell(4, 4), &grid()).unwrap().unwrap();
+
+        assert_eq!(cost, paths.cost);
+        assert_eq!(Some(1), paths.count());
+        assert_eq!(vec![path], paths.paths().collect_vec());
+    }
+
+    #[test]
+    fn a_star_all_several_ends() {
+        let blocked = Grid(vec!["...", "..#", "..."]);
+        let paths = a_star_all(Column(Cell(0, 1)), Column(Cell(2, 1)), &blocked)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(3, paths.cost);
+        assert_eq!(2, paths.ends.len());
+        assert!(paths.ends.contains(&Column(Cell(2, 0))));
+        assert!(paths.ends.contains(&Column(Cell(2, 2))));
+        // Around the wall either along the edge, or through the middle first
+        assert_eq!(Some(4), paths.count());
+        assert_eq!(8, paths.states().len());
+    }
+
+    #[test]
+    fn a_star_all_unreachable() {
+        let walled = Grid(vec!["..#..", "..#.."]);
+
+        assert_eq!(Ok(None), a_star_all(Cell(0, 0), Cell(4, 0), &walled));
+    }
+
+    #[test]
+    fn a_star_with_inconsistent_heuristic() {
+        let consistent = SearchConfig::default();
+        let admissible = SearchConfig {
+            consistency: Consistency::Admissible,
+            ..SearchConfig::default()
+        };
+
+        let (path, stats) = a_star_with(Detour::S, Detour::G, &(), consistent, &mut ()).unwrap();
+        assert_eq!(Some((vec![Detour::S, Detour::A, Detour::G], 9)), path);
+        assert_eq!(
+            SearchStats {
+                expansions: 3,
+                max_frontier: 2
+            },
+            stats
+        );
+
+        let (path, stats) = a_star_with(Detour::S, Detour::G, &(), admissible, &mut ()).unwrap();
+        assert_eq!(
+            Some((vec![Detour::S, Detour::B, Detour::A, Detour::G], 7)),
+            path
+        );
+        assert_eq!(
+            SearchStats {
+                expansions: 4,
+                max_frontier: 2
+            },
+            stats
+        );
+    }
+
+    #[test]
+    fn a_star_with_tie_breaking() {
+        let open = Grid(vec![".....", ".....", ".....", ".....", "....."]);
+        let higher_g = SearchConfig::default();
+        let arbitrary = SearchConfig {
+            tie_breaking: TieBreaking::Arbitrary,
+            ..SearchConfig::default()
+        };
+
+        let (path, stats) = a_star_with(Cell(0, 0), Cell(4, 4), &open, higher_g, &mut ()).unwrap();
+        assert_eq!(8, path.unwrap().1);
+        // Every cell expanded is one step closer to the target
+        assert_eq!(8, stats.expansions);
+
+        let mut counts = Counts::default();
+        let (path, stats) =
+            a_star_with(Cell(0, 0), Cell(4, 4), &open, arbitrary, &mut counts).unwrap();
+        assert_eq!(8, path.unwrap().1);
+        assert!(stats.expansions >= 8);
+        assert_eq!(counts.expanded, stats.expansions);
+        assert!(stats.max_frontier <= counts.pushed);
+    }
+}
@@ -0,0 +1,308 @@
+//! Bidirectional Dijkstra over any [`Graphable`] state, see [`bidirectional`].
+
+use crate::a_star::{
+    Graphable, Node, Observer, Overflow, Path, TieBreaking, checked_add, reconstruct_path,
+};
+use num_traits::{CheckedAdd, Zero};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BinaryHeap;
+
+/// Finds a cheapest path from `start` to `target`, searching forwards from the start through the
+/// [`neighbors`](Graphable::neighbors) and backwards from the target through the [`predecessors`](Graphable::predecessors)
+/// at the same time, until both searches meet.
+/// Each only has to go about half the distance, which on state spaces that grow fast with the distance
+/// leaves far fewer states to remember than [`a_star`](crate::a_star::a_star) without a good heuristic.
+/// The heuristic and [`is_target`](Graphable::is_target) are not used, the target is exactly `target`.
+///
+/// ```
+/// use aoc_util::a_star::Graphable;
+/// use aoc_util::bidirectional::bidirectional;
+///
+/// /// Number that can be doubled for a cost of 1, or incremented for a cost of 2
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Number(u32);
+///
+/// impl Graphable for Number {
+///     type Context = ();
+///     type Cost = u32;
+///
+///     fn neighbors(&self, _: &()) -> Vec<(Self, u32)> {
+///         vec![(Number(self.0 * 2), 1), (Number(self.0 + 1), 2)]
+///     }
+///
+///     fn predecessors(&self, _: &()) -> Vec<(Self, u32)> {
+///         let mut predecessors = vec![];
+///         if self.0 > 1 {
+///             predecessors.push((Number(self.0 - 1), 2));
+///         }
+///         if self.0 % 2 == 0 {
+///             predecessors.push((Number(self.0 / 2), 1));
+///         }
+///         predecessors
+///     }
+/// }
+///
+/// let (path, cost) = bidirectional(Number(1), Number(12), &()).unwrap().unwrap();
+///
+/// assert_eq!(vec![Number(1), Number(2), Number(3), Number(6), Number(12)], path);
+/// assert_eq!(5, cost);
+/// ```
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path exceeds what the cost type can hold.
+pub fn bidirectional<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    bidirectional_observed(start, target, context, &mut ())
+}
+
+/// [`bidirectional`], reporting every state either search expands and pushes to `observer`
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path exceeds what the cost type can hold.
+pub fn bidirectional_observed<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+    observer: &mut impl Observer<T>,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    let mut forward = Side::new(start, observer);
+    let mut backward = Side::new(target, observer);
+    // Cheapest path found so far, as its cost and the state where both searches met
+    let mut meeting: Option<(T::Cost, T)> = forward
+        .g_scores
+        .keys()
+        .find(|state| backward.g_scores.contains_key(state))
+        .map(|state| (T::Cost::zero(), state.clone()));
+
+    while let (Some(forward_g), Some(backward_g)) = (forward.min_g(), backward.min_g()) {
+        // Any path not found yet costs at least as much as the cheapest state left on either side
+        if let Some((cost, _)) = &meeting
+            && forward_g
+                .checked_add(&backward_g)
+                .is_none_or(|bound| bound >= *cost)
+        {
+            break;
+        }
+
+        let (side, other, direction) = match forward_g <= backward_g {
+            true => (&mut forward, &backward, Direction::Forward),
+            false => (&mut backward, &forward, Direction::Backward),
+        };
+        side.expand(other, direction, &mut meeting, context, observer)?;
+    }
+
+    Ok(meeting.map(|(cost, meet)| {
+        let mut path = reconstruct_path(&forward.came_from, meet.clone());
+        let mut current = meet;
+        while let Some(next) = backward.came_from.get(&current) {
+            current = next.clone();
+            path.push(current.clone());
+        }
+        (path, cost)
+    }))
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Direction {
+    Forward,
+    Backward,
+}
+
+/// One of both searches, forwards from the start or backwards from the target
+struct Side<T: Graphable> {
+    open_set: BinaryHeap<Node<T, T::Cost>>,
+    came_from: FxHashMap<T, T>,
+    g_scores: FxHashMap<T, T::Cost>,
+    visited: FxHashSet<T>,
+}
+
+impl<T: Graphable> Side<T> {
+    fn new(origin: T, observer: &mut impl Observer<T>) -> Self {
+        let zero = T::Cost::zero();
+        let mut g_scores = FxHashMap::default();
+        g_scores.insert(origin.clone(), zero);
+        observer.pushed(&origin);
+
+        Self {
+            open_set: BinaryHeap::from([Node::new(origin, zero, zero, TieBreaking::Arbitrary)]),
+            came_from: FxHashMap::default(),
+            g_scores,
+            visited: FxHashSet::default(),
+        }
+    }
+
+    /// Lowest cost of a state still waiting to be expanded, `None` once there are none left
+    fn min_g(&self) -> Option<T::Cost> {
+        self.open_set.peek().map(|node| node.g_score)
+    }
+
+    /// Expands the cheapest state left, updating `meeting` whenever that leads to a cheaper path through the `other` side
+    fn expand(
+        &mut self,
+        other: &Self,
+        direction: Direction,
+        meeting: &mut Option<(T::Cost, T)>,
+        context: &T::Context,
+        observer: &mut impl Observer<T>,
+    ) -> Result<(), Overflow<T>> {
+        let Node {
+            state: current,
+            g_score,
+            ..
+        } = self.open_set.pop().unwrap();
+
+        if self.visited.contains(&current) {
+            return Ok(());
+        }
+        self.visited.insert(current.clone());
+        observer.expanded(&current);
+
+        let steps = match direction {
+            Direction::Forward => current.neighbors(context),
+            Direction::Backward => current.predecessors(context),
+        };
+        for (neighbor, cost) in steps {
+            if self.visited.contains(&neighbor) {
+                continue;
+            }
+
+            let g_score = checked_add(&neighbor, g_score, cost)?;
+
+            if let Some(&existing_g) = self.g_scores.get(&neighbor)
+                && g_score >= existing_g
+            {
+                continue;
+            }
+
+            if let Some(&other_g) = other.g_scores.get(&neighbor) {
+                let cost = checked_add(&neighbor, g_score, other_g)?;
+                if meeting.as_ref().is_none_or(|(best, _)| cost < *best) {
+                    *meeting = Some((cost, neighbor.clone()));
+                }
+            }
+
+            self.came_from.insert(neighbor.clone(), current.clone());
+            self.g_scores.insert(neighbor.clone(), g_score);
+            observer.pushed(&neighbor);
+            self.open_set.push(Node::new(
+                neighbor,
+                g_score,
+                g_score,
+                TieBreaking::Arbitrary,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a_star::{Counts, a_star_observed};
+
+    /// Position on a ring of 100, stepping 1 ahead for a cost of 3 or jumping 7 ahead for a cost of 5
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Position(u8);
+
+    impl Graphable for Position {
+        type Context = ();
+        type Cost = u16;
+
+        fn neighbors(&self, _: &()) -> Vec<(Self, u16)> {
+            vec![
+                (Position((self.0 + 1) % 100), 3),
+                (Position((self.0 + 7) % 100), 5),
+            ]
+        }
+
+        fn predecessors(&self, _: &()) -> Vec<(Self, u16)> {
+            vec![
+                (Position((self.0 + 99) % 100), 3),
+                (Position((self.0 + 93) % 100), 5),
+            ]
+        }
+    }
+
+    #[test]
+    fn bidirectional_matches_a_star() {
+        for target in 0..100 {
+            let expected = a_star_observed(Position(0), Position(target), &(), &mut ())
+                .unwrap()
+                .unwrap();
+            let (path, cost) = bidirectional(Position(0), Position(target), &())
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(expected.1, cost);
+            assert_eq!(Position(0), path[0]);
+            assert_eq!(Position(target), path[path.len() - 1]);
+            let path_cost = path
+                .windows(2)
+                .map(|step| {
+                    step[0]
+                        .neighbors(&())
+                        .into_iter()
+                        .find(|&(next, _)| next == step[1])
+                        .unwrap()
+                        .1
+                })
+                .sum::<u16>();
+            assert_eq!(cost, path_cost);
+        }
+    }
+
+    #[test]
+    fn bidirectional_expands_less() {
+        let mut bidirectional_counts = Counts::default();
+        let mut a_star_counts = Counts::default();
+
+        bidirectional_observed(Position(0), Position(77), &(), &mut bidirectional_counts).unwrap();
+        a_star_observed(Position(0), Position(77), &(), &mut a_star_counts).unwrap();
+
+        assert!(bidirectional_counts.expanded < a_star_counts.expanded);
+        assert!(bidirectional_counts.pushed >= bidirectional_counts.expanded);
+    }
+
+    #[test]
+    fn bidirectional_unreachable() {
+        /// Chain of links that ends after link 5
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        struct Link(u8);
+
+        impl Graphable for Link {
+            type Context = ();
+            type Cost = u8;
+
+            fn neighbors(&self, _: &()) -> Vec<(Self, u8)> {
+                match self.0 < 5 {
+                    true => vec![(Link(self.0 + 1), 1)],
+                    false => vec![],
+                }
+            }
+
+            fn predecessors(&self, _: &()) -> Vec<(Self, u8)> {
+                match self.0 > 0 && self.0 <= 5 {
+                    true => vec![(Link(self.0 - 1), 1)],
+                    false => vec![],
+                }
+            }
+        }
+
+        assert_eq!(Ok(None), bidirectional(Link(2), Link(7), &()));
+        assert_eq!(Ok(None), bidirectional(Link(4), Link(1), &()));
+        assert_eq!(
+            Ok(Some((vec![Link(1), Link(2), Link(3)], 2))),
+            bidirectional(Link(1), Link(3), &())
+        );
+        assert_eq!(
+            Ok(Some((vec![Link(3)], 0))),
+            bidirectional(Link(3), Link(3), &())
+        );
+    }
+}
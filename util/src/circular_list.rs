@@ -1,7 +1,25 @@
+//! Circular doubly-linked list with a cursor, see [`CircularList`].
+
 /// Circular doubly-linked list, backed by a never-shrinking Vector
 /// To avoid the hassle of either `unsafe`
 /// or a hell of `Rc<RefCell<Node<T>>>` and `Weak<RefCell<Node<T>>>`,
 /// we simply store the backing Vector's indices.
+///
+/// All operations happen at the cursor, which moves around the circle in both directions.
+///
+/// ```
+/// use aoc_util::circular_list::CircularList;
+///
+/// let mut list = CircularList::default();
+/// (1..=5).for_each(|el| list.insert_clockwise(el));
+///
+/// list.rotate_counter_clockwise_n(3);
+/// assert_eq!(2, list.remove());
+/// assert_eq!(3, list.remove());
+///
+/// list.rotate_clockwise();
+/// assert_eq!(5, list.remove());
+/// ```
 #[derive(Debug, Default)]
 pub struct CircularList<T> {
     nodes: Vec<Node<T>>,
@@ -50,6 +68,7 @@ impl<T> CircularList<T> {
         }
     }
 
+    /// Moves the cursor to the next node, a no-op on an empty list
     pub fn rotate_clockwise(&mut self) {
         // In the edge case of an empty list (None cursor), rotation should be a no-op
         if let Some(cursor) = self.cursor {
@@ -57,10 +76,12 @@ impl<T> CircularList<T> {
         }
     }
 
+    /// Moves the cursor `n` nodes forward
     pub fn rotate_clockwise_n(&mut self, n: u32) {
         (0..n).for_each(|_| self.rotate_clockwise());
     }
 
+    /// Moves the cursor to the previous node, a no-op on an empty list
     pub fn rotate_counter_clockwise(&mut self) {
         // In the edge case of an empty list (None cursor), rotation should be a no-op
         if let Some(cursor) = self.cursor {
@@ -68,6 +89,7 @@ impl<T> CircularList<T> {
         }
     }
 
+    /// Moves the cursor `n` nodes back
     pub fn rotate_counter_clockwise_n(&mut self, n: u32) {
         (0..n).for_each(|_| self.rotate_counter_clockwise());
     }
@@ -102,3 +124,65 @@ impl<T: Clone> CircularList<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(n: u32) -> CircularList<u32> {
+        let mut list = CircularList::default();
+        (0..n).for_each(|el| list.insert_clockwise(el));
+        list
+    }
+
+    #[test]
+    fn insert_moves_cursor_to_new_node() {
+        let mut list = list(3);
+
+        assert_eq!(2, list.remove());
+        assert_eq!(0, list.remove());
+        assert_eq!(1, list.remove());
+        assert!(list.cursor.is_none());
+    }
+
+    #[test]
+    fn rotate_wraps_around() {
+        let mut list = list(4);
+
+        list.rotate_clockwise_n(6);
+        assert_eq!(1, list.remove());
+
+        list.rotate_counter_clockwise_n(5);
+        assert_eq!(3, list.remove());
+    }
+
+    #[test]
+    fn rotate_empty_is_no_op() {
+        let mut list = CircularList::<u32>::default();
+
+        list.rotate_clockwise();
+        list.rotate_counter_clockwise_n(3);
+        list.insert_clockwise(7);
+
+        assert_eq!(7, list.remove());
+    }
+
+    #[test]
+    fn reuse_after_emptied() {
+        let mut list = list(1);
+
+        assert_eq!(0, list.remove());
+        list.insert_clockwise(1);
+        list.insert_clockwise(2);
+        list.rotate_counter_clockwise();
+
+        assert_eq!(1, list.remove());
+        assert_eq!(2, list.remove());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot call `remove` on an empty list!")]
+    fn remove_from_empty_panics() {
+        CircularList::<u32>::default().remove();
+    }
+}
@@ -0,0 +1,238 @@
+//! Iterative deepening A* over any [`Graphable`] state, see [`ida_star`].
+
+use crate::a_star::{Graphable, Observer, Overflow, Path, checked_add};
+use num_traits::Zero;
+
+/// Finds a cheapest path from `start` to a state that [`is_target`](Graphable::is_target), like [`a_star`](crate::a_star::a_star),
+/// but only ever keeping the current path in memory instead of every state seen so far.
+/// Depth-first searches are repeated with a growing bound on the estimated total cost,
+/// so states get expanded over and over: trading time for memory on state spaces too large to remember.
+///
+/// ```
+/// use aoc_util::a_star::Graphable;
+/// use aoc_util::ida_star::ida_star;
+///
+/// /// Row of lights, toggling a light also toggles its neighbors
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Lights(u32);
+///
+/// impl Graphable for Lights {
+///     type Context = u32;
+///     type Cost = u32;
+///
+///     fn neighbors(&self, &nr_lights: &u32) -> Vec<(Self, u32)> {
+///         let all = (1 << nr_lights) - 1;
+///         (0..nr_lights)
+///             .map(|light| (Lights(self.0 ^ (((0b111 << light) >> 1) & all)), 1))
+///             .collect()
+///     }
+///
+///     fn heuristic(&self, target: &Self, _: &u32) -> u32 {
+///         (self.0 ^ target.0).count_ones().div_ceil(3)
+///     }
+/// }
+///
+/// let (path, presses) = ida_star(Lights(0), Lights(0b11111), &5).unwrap().unwrap();
+///
+/// assert_eq!(2, presses);
+/// assert_eq!(3, path.len());
+/// ```
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+pub fn ida_star<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    ida_star_observed(start, target, context, &mut ())
+}
+
+/// [`ida_star`], reporting every state it expands and pushes onto its path to `observer`
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path, plus the heuristic at its end, exceeds what the cost type can hold.
+pub fn ida_star_observed<T: Graphable>(
+    start: T,
+    target: T,
+    context: &T::Context,
+    observer: &mut impl Observer<T>,
+) -> Result<Option<Path<T>>, Overflow<T>> {
+    let mut bound = checked_add(&start, T::Cost::zero(), start.heuristic(&target, context))?;
+    observer.pushed(&start);
+    let mut path = vec![start];
+
+    loop {
+        match deepen(
+            &mut path,
+            T::Cost::zero(),
+            bound,
+            &target,
+            context,
+            observer,
+        )? {
+            Deepening::Found(cost) => return Ok(Some((path, cost))),
+            Deepening::Exceeded(next_bound) => bound = next_bound,
+            Deepening::Exhausted => return Ok(None),
+        }
+    }
+}
+
+/// Outcome of a single depth-first search bounded on the estimated total cost
+enum Deepening<C> {
+    /// A target was reached at this cost, the path to it is left on the stack
+    Found(C),
+    /// No target within the bound, this is the lowest estimated total cost that went over it
+    Exceeded(C),
+    /// No target reachable at all
+    Exhausted,
+}
+
+/// Depth-first search from the last state of `path`, reached at a cost of `g_score`,
+/// never going past an estimated total cost of `bound`
+fn deepen<T: Graphable>(
+    path: &mut Vec<T>,
+    g_score: T::Cost,
+    bound: T::Cost,
+    target: &T,
+    context: &T::Context,
+    observer: &mut impl Observer<T>,
+) -> Result<Deepening<T::Cost>, Overflow<T>> {
+    let current = path.last().unwrap().clone();
+    let f_score = checked_add(&current, g_score, current.heuristic(target, context))?;
+
+    if f_score > bound {
+        return Ok(Deepening::Exceeded(f_score));
+    }
+    if current.is_target(target) {
+        return Ok(Deepening::Found(g_score));
+    }
+    observer.expanded(&current);
+
+    let mut next_bound: Option<T::Cost> = None;
+    for (neighbor, cost) in current.neighbors(context) {
+        // Only the states on the path are known, so that is the only way to avoid walking in circles
+        if path.contains(&neighbor) {
+            continue;
+        }
+
+        let g_score = checked_add(&neighbor, g_score, cost)?;
+        observer.pushed(&neighbor);
+        path.push(neighbor);
+
+        match deepen(path, g_score, bound, target, context, observer)? {
+            Deepening::Found(cost) => return Ok(Deepening::Found(cost)),
+            Deepening::Exceeded(f_score) => {
+                next_bound = Some(next_bound.map_or(f_score, |bound| bound.min(f_score)));
+            }
+            Deepening::Exhausted => {}
+        }
+
+        path.pop();
+    }
+
+    Ok(next_bound.map_or(Deepening::Exhausted, Deepening::Exceeded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a_star::{Counts, a_star_observed};
+
+    /// Position on a ring of 100, stepping 1 either way for a cost of 3, or jumping 7 ahead for a cost of 5
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Position(u8);
+
+    impl Graphable for Position {
+        type Context = ();
+        type Cost = u8;
+
+        fn neighbors(&self, _: &()) -> Vec<(Self, u8)> {
+            vec![
+                (Position((self.0 + 1) % 100), 3),
+                (Position((self.0 + 99) % 100), 3),
+                (Position((self.0 + 7) % 100), 5),
+            ]
+        }
+
+        fn heuristic(&self, target: &Self, _: &()) -> u8 {
+            let ahead = (target.0 + 100 - self.0) % 100;
+            let behind = (self.0 + 100 - target.0) % 100;
+            ahead.min(behind).div_ceil(7) * 3
+        }
+    }
+
+    /// Chain of links that ends after link 5, each step costing 60
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Link(u8);
+
+    impl Graphable for Link {
+        type Context = ();
+        type Cost = u8;
+
+        fn neighbors(&self, _: &()) -> Vec<(Self, u8)> {
+            match self.0 < 5 {
+                true => vec![(Link(self.0 + 1), 60)],
+                false => vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn ida_star_matches_a_star() {
+        for target in [0, 1, 6, 13, 50, 99] {
+            let expected = a_star_observed(Position(0), Position(target), &(), &mut ()).unwrap();
+            let (path, cost) = ida_star(Position(0), Position(target), &())
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(expected.unwrap().1, cost);
+            assert_eq!(Position(0), path[0]);
+            assert_eq!(Position(target), path[path.len() - 1]);
+        }
+    }
+
+    #[test]
+    fn ida_star_counts() {
+        let mut ida_counts = Counts::default();
+        let mut a_star_counts = Counts::default();
+
+        ida_star_observed(Link(0), Link(3), &(), &mut ida_counts).unwrap();
+        a_star_observed(Link(0), Link(3), &(), &mut a_star_counts).unwrap();
+
+        // Bounds 0, 60, 120 and 180, each deepening expanding the links before the bound again
+        assert_eq!(
+            Counts {
+                expanded: 9,
+                pushed: 10
+            },
+            ida_counts
+        );
+        assert_eq!(
+            Counts {
+                expanded: 3,
+                pushed: 4
+            },
+            a_star_counts
+        );
+    }
+
+    #[test]
+    fn ida_star_exhausted() {
+        assert_eq!(Ok(None), ida_star(Link(2), Link(6), &()));
+        assert_eq!(
+            Ok(Some((vec![Link(5)], 0))),
+            ida_star(Link(5), Link(5), &())
+        );
+    }
+
+    #[test]
+    fn ida_star_overflow() {
+        assert_eq!(
+            Err(Overflow { state: Link(5) }),
+            ida_star(Link(0), Link(5), &())
+        );
+    }
+}
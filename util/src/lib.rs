@@ -0,0 +1,64 @@
+//! Reusable helpers for solving Advent of Code puzzles, independent of any year:
+//! A*, IDA*, bidirectional, Dijkstra and breadth-first search over a [`Graphable`](a_star::Graphable) state,
+//! a [`UnionFind`](union_find::UnionFind), a [`CircularList`](circular_list::CircularList), and plain number theory.
+#![warn(missing_docs)]
+
+pub mod a_star;
+pub mod bidirectional;
+pub mod circular_list;
+pub mod ida_star;
+pub mod search;
+pub mod union_find;
+
+/// Radix of decimal digits, as taken by [`char::to_digit`] and [`char::from_digit`]
+pub const BASE_10: u32 = 10;
+
+/// Least common multiple of `a` and `b`
+///
+/// ```
+/// assert_eq!(36, aoc_util::lcm(12, 18));
+/// assert_eq!(0, aoc_util::lcm(0, 7));
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    a / gcd(a, b) * b
+}
+
+/// Greatest common divisor of `a` and `b`, using Euclid's algorithm
+///
+/// ```
+/// assert_eq!(6, aoc_util::gcd(12, 18));
+/// assert_eq!(7, aoc_util::gcd(0, 7));
+/// ```
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let mut pair = (a, b);
+
+    while pair.1 > 0 {
+        pair = (pair.1, pair.0 % pair.1)
+    }
+
+    pair.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(1, gcd(17, 5));
+        assert_eq!(5, gcd(5, 0));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(85, lcm(17, 5));
+        assert_eq!(18, lcm(6, 18));
+        assert_eq!(0, lcm(0, 0));
+    }
+
+    #[test]
+    fn base_10_parses_digits() {
+        assert_eq!(Some(7), '7'.to_digit(BASE_10));
+        assert_eq!(None, 'a'.to_digit(BASE_10));
+    }
+}
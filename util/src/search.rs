@@ -0,0 +1,331 @@
+//! Searches without a single target over any [`Graphable`] state, see [`dijkstra`] and [`bfs`].
+
+use crate::a_star::{Graphable, Node, Overflow, TieBreaking, checked_add, reconstruct_path};
+use num_traits::Zero;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+
+/// Outcome of a search from one or more starts: a distance map and a predecessor tree
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Search<T: Eq + Hash, C> {
+    /// Distance from the nearest start of every state the search reached
+    pub distances: FxHashMap<T, C>,
+    /// Previous state along a shortest path from a start, for every reached state but the starts
+    pub predecessors: FxHashMap<T, T>,
+    /// State that ended a search `_until` some predicate, `None` if the search ran out of states
+    pub found: Option<T>,
+}
+
+impl<T: Graphable, C: Copy> Search<T, C> {
+    /// Distance of `state` from the nearest start, or `None` if the search did not reach it
+    pub fn distance(&self, state: &T) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Every state along a shortest path from a start to `state` (both ends included),
+    /// or `None` if the search did not reach it
+    pub fn path(&self, state: &T) -> Option<Vec<T>> {
+        self.distances
+            .contains_key(state)
+            .then(|| reconstruct_path(&self.predecessors, state.clone()))
+    }
+}
+
+/// Dijkstra's algorithm, finding the cost of a cheapest path from any of the `starts` to every reachable state
+///
+/// ```
+/// use aoc_util::a_star::Graphable;
+/// use aoc_util::search::dijkstra;
+///
+/// /// Floor of a building, taking the stairs up costs 3 while the elevator up 5 floors costs 4
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Floor(u8);
+///
+/// impl Graphable for Floor {
+///     type Context = u8;
+///     type Cost = u32;
+///
+///     fn neighbors(&self, &top: &u8) -> Vec<(Self, u32)> {
+///         [(self.0 + 1, 3), (self.0 + 5, 4)]
+///             .into_iter()
+///             .filter(|&(floor, _)| floor <= top)
+///             .map(|(floor, cost)| (Floor(floor), cost))
+///             .collect()
+///     }
+/// }
+///
+/// let search = dijkstra([Floor(0)], &10).unwrap();
+///
+/// assert_eq!(Some(10), search.distance(&Floor(7)));
+/// assert_eq!(Some(vec![Floor(0), Floor(5), Floor(10)]), search.path(&Floor(10)));
+/// ```
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path exceeds what the cost type can hold.
+pub fn dijkstra<T: Graphable>(
+    starts: impl IntoIterator<Item = T>,
+    context: &T::Context,
+) -> Result<Search<T, T::Cost>, Overflow<T>> {
+    dijkstra_until(starts, context, |_| false)
+}
+
+/// Dijkstra's algorithm, stopping at the first state matching `until`, which is then a nearest such state.
+/// Only the states closer to the starts than that one are guaranteed to be in the distance map.
+///
+/// # Errors
+///
+/// [`Overflow`] as soon as the cost of a path exceeds what the cost type can hold.
+pub fn dijkstra_until<T: Graphable>(
+    starts: impl IntoIterator<Item = T>,
+    context: &T::Context,
+    mut until: impl FnMut(&T) -> bool,
+) -> Result<Search<T, T::Cost>, Overflow<T>> {
+    let mut open_set = BinaryHeap::new();
+    let mut came_from: FxHashMap<T, T> = FxHashMap::default();
+    let mut g_scores: FxHashMap<T, T::Cost> = FxHashMap::default();
+    let mut visited: FxHashSet<T> = FxHashSet::default();
+    let mut found = None;
+
+    for start in starts {
+        g_scores.insert(start.clone(), T::Cost::zero());
+        let zero = T::Cost::zero();
+        open_set.push(Node::new(start, zero, zero, TieBreaking::Arbitrary));
+    }
+
+    while let Some(Node {
+        state: current,
+        g_score,
+        ..
+    }) = open_set.pop()
+    {
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.insert(current.clone());
+
+        if until(&current) {
+            found = Some(current);
+            break;
+        }
+
+        for (neighbor, cost) in current.neighbors(context) {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+
+            let g_score = checked_add(&neighbor, g_score, cost)?;
+
+            if let Some(&existing_g) = g_scores.get(&neighbor)
+                && g_score >= existing_g
+            {
+                continue;
+            }
+
+            came_from.insert(neighbor.clone(), current.clone());
+            g_scores.insert(neighbor.clone(), g_score);
+            open_set.push(Node::new(
+                neighbor,
+                g_score,
+                g_score,
+                TieBreaking::Arbitrary,
+            ));
+        }
+    }
+
+    // Only keep the final distances, not those still waiting in the open set
+    g_scores.retain(|state, _| visited.contains(state));
+    came_from.retain(|state, _| visited.contains(state));
+
+    Ok(Search {
+        distances: g_scores,
+        predecessors: came_from,
+        found,
+    })
+}
+
+/// Breadth-first search, finding the number of steps from any of the `starts` to every reachable state.
+/// The cost of each step is ignored, every step counts as one.
+pub fn bfs<T: Graphable>(
+    starts: impl IntoIterator<Item = T>,
+    context: &T::Context,
+) -> Search<T, usize> {
+    bfs_until(starts, context, |_| false)
+}
+
+/// Breadth-first search, stopping at the first state matching `until`, which is then a nearest such state
+///
+/// ```
+/// use aoc_util::a_star::Graphable;
+/// use aoc_util::search::bfs_until;
+///
+/// /// Number that can either be doubled or incremented
+/// #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+/// struct Number(u32);
+///
+/// impl Graphable for Number {
+///     type Context = ();
+///     type Cost = u32;
+///
+///     fn neighbors(&self, _: &()) -> Vec<(Self, u32)> {
+///         vec![(Number(self.0 * 2), 1), (Number(self.0 + 1), 1)]
+///     }
+/// }
+///
+/// let search = bfs_until([Number(1)], &(), |number| number.0 == 10);
+///
+/// assert_eq!(Some(Number(10)), search.found);
+/// assert_eq!(Some(4), search.distance(&Number(10)));
+/// ```
+pub fn bfs_until<T: Graphable>(
+    starts: impl IntoIterator<Item = T>,
+    context: &T::Context,
+    mut until: impl FnMut(&T) -> bool,
+) -> Search<T, usize> {
+    let mut to_visit = VecDeque::new();
+    let mut distances: FxHashMap<T, usize> = FxHashMap::default();
+    let mut predecessors: FxHashMap<T, T> = FxHashMap::default();
+    let mut found = None;
+
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            to_visit.push_back(start);
+        }
+    }
+
+    while let Some(current) = to_visit.pop_front() {
+        if until(&current) {
+            found = Some(current);
+            break;
+        }
+
+        let distance = distances[&current];
+        for (neighbor, _) in current.neighbors(context) {
+            if distances.contains_key(&neighbor) {
+                continue;
+            }
+
+            distances.insert(neighbor.clone(), distance + 1);
+            predecessors.insert(neighbor.clone(), current.clone());
+            to_visit.push_back(neighbor);
+        }
+    }
+
+    Search {
+        distances,
+        predecessors,
+        found,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directed graph as a list of weighted edges `(from, to, cost)`
+    struct Edges(Vec<(char, char, u8)>);
+
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    struct Vertex(char);
+
+    impl Graphable for Vertex {
+        type Context = Edges;
+        type Cost = u8;
+
+        fn neighbors(&self, edges: &Edges) -> Vec<(Self, u8)> {
+            edges
+                .0
+                .iter()
+                .filter(|&&(from, _, _)| from == self.0)
+                .map(|&(_, to, cost)| (Vertex(to), cost))
+                .collect()
+        }
+    }
+
+    /// `a` reaches `d` directly at a cost of 10, or through `b` and `c` at a cost of 3, `e` is unreachable
+    fn edges() -> Edges {
+        Edges(vec![
+            ('a', 'd', 10),
+            ('a', 'b', 1),
+            ('b', 'c', 1),
+            ('c', 'd', 1),
+            ('d', 'f', 100),
+            ('e', 'f', 1),
+        ])
+    }
+
+    #[test]
+    fn dijkstra_distances_and_paths() {
+        let search = dijkstra([Vertex('a')], &edges()).unwrap();
+
+        assert_eq!(Some(0), search.distance(&Vertex('a')));
+        assert_eq!(Some(3), search.distance(&Vertex('d')));
+        assert_eq!(Some(103), search.distance(&Vertex('f')));
+        assert_eq!(None, search.distance(&Vertex('e')));
+        assert_eq!(
+            Some(vec![Vertex('a'), Vertex('b'), Vertex('c'), Vertex('d')]),
+            search.path(&Vertex('d'))
+        );
+        assert_eq!(None, search.path(&Vertex('e')));
+        assert_eq!(None, search.found);
+    }
+
+    #[test]
+    fn dijkstra_multi_source() {
+        let search = dijkstra([Vertex('a'), Vertex('e')], &edges()).unwrap();
+
+        assert_eq!(Some(0), search.distance(&Vertex('e')));
+        assert_eq!(Some(1), search.distance(&Vertex('f')));
+        assert_eq!(
+            Some(vec![Vertex('e'), Vertex('f')]),
+            search.path(&Vertex('f'))
+        );
+        assert_eq!(4, search.predecessors.len());
+    }
+
+    #[test]
+    fn dijkstra_until_stops_early() {
+        let search = dijkstra_until([Vertex('a')], &edges(), |&Vertex(v)| v >= 'c').unwrap();
+
+        assert_eq!(Some(Vertex('c')), search.found);
+        assert_eq!(Some(2), search.distance(&Vertex('c')));
+        assert_eq!(None, search.distance(&Vertex('d')));
+    }
+
+    #[test]
+    fn dijkstra_overflow() {
+        let edges = Edges(vec![('a', 'b', 200), ('b', 'c', 100)]);
+
+        assert_eq!(
+            Err(Overflow { state: Vertex('c') }),
+            dijkstra([Vertex('a')], &edges)
+        );
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let search = bfs([Vertex('a')], &edges());
+
+        assert_eq!(Some(1), search.distance(&Vertex('d')));
+        assert_eq!(Some(2), search.distance(&Vertex('f')));
+        assert_eq!(None, search.distance(&Vertex('e')));
+        assert_eq!(
+            Some(vec![Vertex('a'), Vertex('d'), Vertex('f')]),
+            search.path(&Vertex('f'))
+        );
+    }
+
+    #[test]
+    fn bfs_until_stops_early() {
+        let search = bfs_until([Vertex('e'), Vertex('a')], &edges(), |&Vertex(v)| v == 'f');
+
+        assert_eq!(Some(Vertex('f')), search.found);
+        assert_eq!(Some(1), search.distance(&Vertex('f')));
+        assert_eq!(
+            Some(vec![Vertex('e'), Vertex('f')]),
+            search.path(&Vertex('f'))
+        );
+    }
+}
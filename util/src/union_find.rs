@@ -0,0 +1,129 @@
+//! Disjoint sets of indices, see [`UnionFind`].
+
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+/// Disjoint sets over the indices `0..n`, starting out as `n` singletons,
+/// merged by union by rank with path compression for near-constant time operations.
+///
+/// ```
+/// use aoc_util::union_find::UnionFind;
+///
+/// let mut uf = UnionFind::new(5);
+/// uf.union(0, 3);
+/// uf.union(3, 4);
+///
+/// assert_eq!(uf.find(0), uf.find(4));
+/// assert_eq!(3, uf.set_count());
+/// assert_eq!(vec![vec![0, 3, 4], vec![1], vec![2]], {
+///     let mut sets = uf.sets();
+///     sets.sort();
+///     sets
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets, one per index
+    pub fn new(n: usize) -> Self {
+        UnionFind {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+            set_count: n,
+        }
+    }
+
+    /// Representative of the set containing `idx`, the same for every index in that set
+    pub fn find(&mut self, idx: usize) -> usize {
+        // Path compression
+        if self.parents[idx] != idx {
+            self.parents[idx] = self.find(self.parents[idx]);
+        }
+        self.parents[idx]
+    }
+
+    /// Merges the sets containing `idx_1` and `idx_2`, a no-op if they are already the same
+    pub fn union(&mut self, idx_1: usize, idx_2: usize) {
+        let root_1 = self.find(idx_1);
+        let root_2 = self.find(idx_2);
+
+        if root_1 != root_2 {
+            // Union by rank
+            match self.ranks[root_1].cmp(&self.ranks[root_2]) {
+                Ordering::Less => self.parents[root_1] = root_2,
+                Ordering::Greater => self.parents[root_2] = root_1,
+                Ordering::Equal => {
+                    self.parents[root_2] = root_1;
+                    self.ranks[root_1] += 1;
+                }
+            }
+            self.set_count -= 1;
+        }
+    }
+
+    /// Number of disjoint sets left
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// All disjoint sets, each with its indices in ascending order
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        (0..self.parents.len())
+            .map(|idx| (self.find(idx), idx))
+            .sorted_by_key(|&(root, _)| root)
+            .chunk_by(|&(root, _)| root)
+            .into_iter()
+            .map(|(_, set)| set.into_iter().map(move |(_, idx)| idx).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_merges_sets() {
+        let mut uf = UnionFind::new(6);
+
+        assert_eq!(6, uf.set_count());
+        assert!((0..6).all(|idx| uf.find(idx) == idx));
+
+        uf.union(0, 1);
+        uf.union(2, 3);
+        uf.union(1, 3);
+
+        assert_eq!(3, uf.set_count());
+        assert_eq!(uf.find(0), uf.find(2));
+        assert_ne!(uf.find(0), uf.find(4));
+    }
+
+    #[test]
+    fn union_same_set_is_no_op() {
+        let mut uf = UnionFind::new(3);
+        uf.union(0, 1);
+        uf.union(1, 0);
+        uf.union(2, 2);
+
+        assert_eq!(2, uf.set_count());
+    }
+
+    #[test]
+    fn sets_groups_indices() {
+        let mut uf = UnionFind::new(5);
+        uf.union(4, 1);
+        uf.union(0, 2);
+        uf.union(2, 4);
+
+        assert_eq!(
+            vec![vec![0, 1, 2, 4], vec![3]],
+            uf.sets().into_iter().sorted().collect_vec()
+        );
+        assert!(UnionFind::new(0).sets().is_empty());
+    }
+}